@@ -0,0 +1,353 @@
+use serde::{Deserialize, Serialize};
+
+/// One application capable of opening an arbitrary path, as surfaced by the
+/// generic "Open With" picker. `GitApp` stays the curated/prioritized subset
+/// shown first in the UI; this covers everything else installed on the system.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppOption {
+    pub id: String,
+    pub display_name: String,
+    pub icon_path: Option<String>,
+}
+
+pub struct AppOpener;
+
+impl AppOpener {
+    /// Enumerate every application registered to open a directory.
+    pub fn list_apps() -> Vec<AppOption> {
+        #[cfg(target_os = "macos")]
+        {
+            macos::list_apps()
+        }
+        #[cfg(target_os = "linux")]
+        {
+            linux::list_apps()
+        }
+        #[cfg(target_os = "windows")]
+        {
+            windows::list_apps()
+        }
+    }
+
+    /// Open `path` with the app identified by `app_id` (as returned by `list_apps`).
+    pub fn open_with(path: &str, app_id: &str) -> Result<(), String> {
+        #[cfg(target_os = "macos")]
+        {
+            macos::open_with(path, app_id)
+        }
+        #[cfg(target_os = "linux")]
+        {
+            linux::open_with(path, app_id)
+        }
+        #[cfg(target_os = "windows")]
+        {
+            windows::open_with(path, app_id)
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod macos {
+    use super::AppOption;
+    use std::process::Command;
+
+    /// Query LaunchServices for every app registered to open a directory.
+    /// There's no pure-Rust LaunchServices binding, so this drives it
+    /// through a JXA (JavaScript for Automation) bridge: the modern
+    /// `NSWorkspace.urlsForApplicationsToOpenContentType` API is tried
+    /// first, falling back to the older `LSCopyApplicationURLsForURL` C
+    /// function for systems that predate `UTType`.
+    pub fn list_apps() -> Vec<AppOption> {
+        let script = r#"
+            ObjC.import('AppKit');
+            ObjC.import('CoreServices');
+
+            function appUrlsForFolder() {
+                const workspace = $.NSWorkspace.sharedWorkspace;
+                if (workspace.urlsForApplicationsToOpenContentType && $.UTTypeFolder) {
+                    return workspace.urlsForApplicationsToOpenContentType($.UTTypeFolder);
+                }
+                const homeUrl = $.NSURL.fileURLWithPath($.NSHomeDirectory());
+                return $.LSCopyApplicationURLsForURL(homeUrl, $.kLSRolesAll);
+            }
+
+            const urls = appUrlsForFolder();
+            const lines = [];
+            const count = urls.count;
+            for (let i = 0; i < count; i++) {
+                const appUrl = urls.objectAtIndex(i);
+                const bundle = $.NSBundle.bundleWithURL(appUrl);
+                if (!bundle || !bundle.bundleIdentifier) continue;
+                const id = ObjC.unwrap(bundle.bundleIdentifier);
+                const name = ObjC.unwrap($.NSFileManager.defaultManager.displayNameAtPath(appUrl.path));
+                lines.push([id, name || id, ObjC.unwrap(appUrl.path)].join('|'));
+            }
+            lines.join('\n');
+        "#;
+
+        let output = Command::new("osascript").arg("-l").arg("JavaScript").arg("-e").arg(script).output();
+
+        let Ok(output) = output else {
+            return Vec::new();
+        };
+        if !output.status.success() {
+            return Vec::new();
+        }
+
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(parse_app_line)
+            .collect()
+    }
+
+    fn parse_app_line(line: &str) -> Option<AppOption> {
+        let mut parts = line.splitn(3, '|');
+        let id = parts.next()?.to_string();
+        let display_name = parts.next()?.to_string();
+        let icon_path = parts.next().map(|p| p.to_string());
+
+        if id.is_empty() {
+            return None;
+        }
+
+        Some(AppOption { id, display_name, icon_path })
+    }
+
+    pub fn open_with(path: &str, app_id: &str) -> Result<(), String> {
+        let output = Command::new("open")
+            .arg("-b")
+            .arg(app_id)
+            .arg(path)
+            .output()
+            .map_err(|e| format!("Failed to launch {}: {}", app_id, e))?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            Err(format!("Failed to open with {}: {}", app_id, String::from_utf8_lossy(&output.stderr)))
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod linux {
+    use super::AppOption;
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+    use std::process::Command;
+
+    fn desktop_dirs() -> Vec<PathBuf> {
+        let mut dirs = vec![PathBuf::from("/usr/share/applications"), PathBuf::from("/usr/local/share/applications")];
+
+        if let Ok(home) = std::env::var("HOME") {
+            dirs.push(PathBuf::from(home).join(".local/share/applications"));
+        }
+        if let Ok(xdg_data_dirs) = std::env::var("XDG_DATA_DIRS") {
+            dirs.extend(xdg_data_dirs.split(':').filter(|d| !d.is_empty()).map(|d| PathBuf::from(d).join("applications")));
+        }
+
+        dirs
+    }
+
+    /// The subset of the Desktop Entry spec's `[Desktop Entry]` section we need.
+    struct DesktopEntry {
+        name: String,
+        exec: String,
+        icon: Option<String>,
+        mime_types: Vec<String>,
+        no_display: bool,
+    }
+
+    fn parse_desktop_entry(contents: &str) -> Option<DesktopEntry> {
+        let mut in_section = false;
+        let mut name = None;
+        let mut exec = None;
+        let mut icon = None;
+        let mut mime_types = Vec::new();
+        let mut no_display = false;
+
+        for line in contents.lines() {
+            let line = line.trim();
+            if line.starts_with('[') {
+                in_section = line == "[Desktop Entry]";
+                continue;
+            }
+            if !in_section {
+                continue;
+            }
+
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            let value = value.trim();
+
+            match key.trim() {
+                "Name" => name = Some(value.to_string()),
+                "Exec" => exec = Some(value.to_string()),
+                "Icon" => icon = Some(value.to_string()),
+                "MimeType" => mime_types = value.split(';').filter(|s| !s.is_empty()).map(str::to_string).collect(),
+                "NoDisplay" if value.eq_ignore_ascii_case("true") => no_display = true,
+                "Hidden" if value.eq_ignore_ascii_case("true") => no_display = true,
+                _ => {}
+            }
+        }
+
+        Some(DesktopEntry {
+            name: name?,
+            exec: exec?,
+            icon,
+            mime_types,
+            no_display,
+        })
+    }
+
+    fn handles_directories(entry: &DesktopEntry) -> bool {
+        entry.mime_types.iter().any(|m| m == "inode/directory")
+    }
+
+    pub fn list_apps() -> Vec<AppOption> {
+        let mut apps = HashMap::new();
+
+        for dir in desktop_dirs() {
+            let Ok(entries) = std::fs::read_dir(&dir) else {
+                continue;
+            };
+
+            for entry in entries.flatten() {
+                let path = entry.path();
+                if path.extension().and_then(|e| e.to_str()) != Some("desktop") {
+                    continue;
+                }
+
+                let Some(id) = path.file_name().and_then(|n| n.to_str()).map(str::to_string) else {
+                    continue;
+                };
+                // The same .desktop id can appear in multiple XDG data dirs;
+                // the first one found (highest-priority dir) wins.
+                if apps.contains_key(&id) {
+                    continue;
+                }
+
+                let Ok(contents) = std::fs::read_to_string(&path) else {
+                    continue;
+                };
+                let Some(desktop_entry) = parse_desktop_entry(&contents) else {
+                    continue;
+                };
+                if desktop_entry.no_display || !handles_directories(&desktop_entry) {
+                    continue;
+                }
+
+                apps.insert(
+                    id.clone(),
+                    AppOption {
+                        id,
+                        display_name: desktop_entry.name,
+                        icon_path: desktop_entry.icon,
+                    },
+                );
+            }
+        }
+
+        apps.into_values().collect()
+    }
+
+    fn find_desktop_entry(app_id: &str) -> Option<DesktopEntry> {
+        desktop_dirs().iter().find_map(|dir| {
+            let contents = std::fs::read_to_string(dir.join(app_id)).ok()?;
+            parse_desktop_entry(&contents)
+        })
+    }
+
+    pub fn open_with(path: &str, app_id: &str) -> Result<(), String> {
+        let entry = find_desktop_entry(app_id).ok_or_else(|| format!("No .desktop entry found for {}", app_id))?;
+
+        // Field codes (%f, %U, %i, ...) describe how the launching app-menu
+        // substitutes arguments; we're passing one fixed path, so drop them.
+        let mut tokens = entry.exec.split_whitespace().filter(|t| !t.starts_with('%'));
+        let binary = tokens.next().ok_or_else(|| format!("{} has an empty Exec line", app_id))?;
+
+        let mut command = Command::new(binary);
+        command.args(tokens);
+        command.arg(path);
+        crate::linux_env::apply_to_command(&mut command);
+
+        command.spawn().map(|_| ()).map_err(|e| format!("Failed to launch {}: {}", app_id, e))
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod windows {
+    use super::AppOption;
+    use std::process::Command;
+    use winreg::enums::HKEY_CLASSES_ROOT;
+    use winreg::RegKey;
+
+    /// Windows models "things that can open a folder" as verbs registered
+    /// under `Directory\shell` (and the legacy `Folder\shell` alias) in the
+    /// classes root, each with a `command` subkey holding the actual invocation.
+    const SHELL_ROOTS: &[&str] = &[r"Directory\shell", r"Folder\shell"];
+
+    pub fn list_apps() -> Vec<AppOption> {
+        let classes_root = RegKey::predef(HKEY_CLASSES_ROOT);
+        let mut apps = Vec::new();
+
+        for root in SHELL_ROOTS {
+            let Ok(shell) = classes_root.open_subkey(root) else {
+                continue;
+            };
+
+            for verb in shell.enum_keys().flatten() {
+                let Ok(verb_key) = shell.open_subkey(&verb) else {
+                    continue;
+                };
+                if verb_key.open_subkey("command").is_err() {
+                    continue;
+                }
+
+                let display_name: String = verb_key.get_value("MUIVerb").or_else(|_| verb_key.get_value("")).unwrap_or_else(|_| verb.clone());
+                let icon_path: Option<String> = verb_key.get_value("Icon").ok();
+
+                apps.push(AppOption {
+                    id: format!(r"{}\{}", root, verb),
+                    display_name,
+                    icon_path,
+                });
+            }
+        }
+
+        apps
+    }
+
+    pub fn open_with(path: &str, app_id: &str) -> Result<(), String> {
+        if !SHELL_ROOTS.iter().any(|root| app_id.starts_with(root)) {
+            return Err(format!("Unrecognized app id: {}", app_id));
+        }
+
+        let classes_root = RegKey::predef(HKEY_CLASSES_ROOT);
+        let command_line: String = classes_root
+            .open_subkey(format!(r"{}\command", app_id))
+            .and_then(|key| key.get_value(""))
+            .map_err(|e| format!("Failed to resolve command for {}: {}", app_id, e))?;
+
+        // Commands are stored like `"C:\...\app.exe" "%1"`; split on the
+        // closing quote of the binary path to separate it from its arguments.
+        let binary = command_line
+            .strip_prefix('"')
+            .and_then(|rest| rest.split_once('"'))
+            .map(|(binary, _)| binary)
+            .unwrap_or(command_line.as_str());
+
+        Command::new(binary).arg(path).spawn().map(|_| ()).map_err(|e| format!("Failed to launch {}: {}", app_id, e))
+    }
+}
+
+#[tauri::command]
+pub fn list_open_with_apps() -> Vec<AppOption> {
+    AppOpener::list_apps()
+}
+
+#[tauri::command]
+pub fn open_path_with(path: String, app_id: String) -> Result<(), String> {
+    AppOpener::open_with(&path, &app_id)
+}
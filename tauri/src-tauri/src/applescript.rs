@@ -229,21 +229,46 @@ impl AppleScriptTerminalLauncher {
             "iTerm2" | "iTerm" => {
                 AppleScriptRunner::launch_iterm2(session_id, Some(&full_command), true)
             }
-            _ => {
-                // For other terminals, try to launch via open command
-                let mut cmd = Command::new("open");
-                cmd.arg("-a").arg(terminal_type);
-                
+            _ => Self::launch_unsupported_terminal(terminal_type, &full_command, working_directory),
+        }
+    }
+
+    /// Launch a terminal VibeTunnel can't drive via AppleScript, consulting
+    /// `TerminalDetector` for the correct CLI invocation (e.g. `wezterm
+    /// start`, `alacritty -e`) instead of blindly shelling out to `open -a`.
+    fn launch_unsupported_terminal(
+        terminal_type: &str,
+        command: &str,
+        working_directory: Option<&str>,
+    ) -> Result<String, String> {
+        if let Some(detected) = crate::terminal_detector::TerminalDetector::find(terminal_type) {
+            if let crate::terminal_detector::LaunchStrategy::Cli { binary, args } = detected.strategy {
+                let mut cli = Command::new(&binary);
+                cli.args(&args);
                 if let Some(cwd) = working_directory {
-                    cmd.arg("--args").arg("--working-directory").arg(cwd);
+                    cli.current_dir(cwd);
                 }
-                
-                cmd.output()
-                    .map_err(|e| format!("Failed to launch {}: {}", terminal_type, e))?;
-                
-                Ok(String::new())
+                cli.arg("bash").arg("-c").arg(command);
+
+                return cli
+                    .spawn()
+                    .map(|child| child.id().to_string())
+                    .map_err(|e| format!("Failed to launch {}: {}", binary, e));
             }
         }
+
+        // Fall back to the generic `open -a` invocation for anything unrecognized.
+        let mut cmd = Command::new("open");
+        cmd.arg("-a").arg(terminal_type);
+
+        if let Some(cwd) = working_directory {
+            cmd.arg("--args").arg("--working-directory").arg(cwd);
+        }
+
+        cmd.output()
+            .map_err(|e| format!("Failed to launch {}: {}", terminal_type, e))?;
+
+        Ok(String::new())
     }
 
     /// Focus a terminal window using AppleScript
@@ -274,4 +299,129 @@ impl AppleScriptTerminalLauncher {
             }
         }
     }
+
+    /// Launch several commands at once, each placed into its own tab or window.
+    ///
+    /// Returns one window/tab identifier per entry, in the same order as
+    /// `layout`, suitable for later use with `focus_terminal_window`. For
+    /// Terminal.app each identifier is the id of the window holding the entry,
+    /// so entries placed as tabs of one window share an identifier.
+    pub async fn launch_layout(
+        terminal_type: &str,
+        layout: &[LayoutEntry],
+    ) -> Result<Vec<String>, String> {
+        if layout.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        info!("Launching layout of {} entries in {}", layout.len(), terminal_type);
+
+        match terminal_type {
+            "Terminal" | "Terminal.app" => Self::launch_layout_terminal_app(layout),
+            "iTerm2" | "iTerm" => Self::launch_layout_iterm2(layout),
+            _ => Err(format!("launch_layout is not supported for terminal type: {}", terminal_type)),
+        }
+    }
+
+    fn launch_layout_terminal_app(layout: &[LayoutEntry]) -> Result<Vec<String>, String> {
+        let mut script = String::new();
+        script.push_str("tell application \"Terminal\"\n");
+        script.push_str("    activate\n");
+        script.push_str("    set resultList to {}\n");
+
+        for (index, entry) in layout.iter().enumerate() {
+            let command = entry.full_command();
+            let escaped = command.replace('\"', "\\\"");
+
+            match entry.placement {
+                WindowPlacement::NewWindow => {
+                    script.push_str(&format!(
+                        "    do script \"{}\"\n    set w{} to front window\n    set t{} to selected tab of w{}\n",
+                        escaped, index, index, index
+                    ));
+                }
+                WindowPlacement::NewTab if index == 0 => {
+                    script.push_str(&format!(
+                        "    do script \"{}\"\n    set w{} to front window\n    set t{} to selected tab of w{}\n",
+                        escaped, index, index, index
+                    ));
+                }
+                WindowPlacement::NewTab => {
+                    script.push_str(&format!(
+                        "    tell application \"System Events\" to keystroke \"t\" using command down\n    delay 0.2\n    do script \"{}\" in front window\n    set w{} to front window\n    set t{} to selected tab of w{}\n",
+                        escaped, index, index, index
+                    ));
+                }
+            }
+
+            script.push_str(&format!(
+                "    set end of resultList to ((id of w{}) as string)\n",
+                index
+            ));
+        }
+
+        script.push_str("    return resultList\nend tell");
+
+        // osascript prints a returned list as one comma-separated line.
+        let output = AppleScriptRunner::run_script(&script)?;
+        Ok(output.split(',').map(|l| l.trim().to_string()).filter(|l| !l.is_empty()).collect())
+    }
+
+    fn launch_layout_iterm2(layout: &[LayoutEntry]) -> Result<Vec<String>, String> {
+        let mut script = String::new();
+        script.push_str("tell application \"iTerm\"\n");
+        script.push_str("    activate\n");
+        script.push_str("    set resultList to {}\n");
+
+        for (index, entry) in layout.iter().enumerate() {
+            let command = entry.full_command();
+            let escaped = command.replace('\"', "\\\"");
+
+            match entry.placement {
+                WindowPlacement::NewWindow => {
+                    script.push_str(&format!(
+                        "    set w{} to (create window with default profile)\n    tell current session of w{} to write text \"{}\"\n    set id{} to id of w{} as string\n",
+                        index, index, escaped, index, index
+                    ));
+                }
+                WindowPlacement::NewTab => {
+                    script.push_str(&format!(
+                        "    tell current window\n        set t{} to (create tab with default profile)\n        tell current session of t{} to write text \"{}\"\n        set id{} to id of t{} as string\n    end tell\n",
+                        index, index, escaped, index, index
+                    ));
+                }
+            }
+
+            script.push_str(&format!("    set end of resultList to id{}\n", index));
+        }
+
+        script.push_str("    return resultList\nend tell");
+
+        let output = AppleScriptRunner::run_script(&script)?;
+        Ok(output.split(',').map(|l| l.trim().to_string()).filter(|l| !l.is_empty()).collect())
+    }
+}
+
+/// Where a layout entry's command should be placed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowPlacement {
+    NewTab,
+    NewWindow,
+}
+
+/// One command to launch as part of a `launch_layout` call.
+#[derive(Debug, Clone)]
+pub struct LayoutEntry {
+    pub command: String,
+    pub working_directory: Option<String>,
+    pub placement: WindowPlacement,
+}
+
+impl LayoutEntry {
+    fn full_command(&self) -> String {
+        match &self.working_directory {
+            Some(cwd) => format!("cd '{}' && {}", cwd, self.command),
+            None => self.command.clone(),
+        }
+    }
 }
\ No newline at end of file
@@ -2,6 +2,60 @@ use serde::{Deserialize, Serialize};
 use std::process::Command;
 use tracing::info;
 
+/// How a resolved Linux binary is actually packaged, so `open_repository`
+/// knows how to invoke it rather than assuming it's a plain executable.
+#[cfg(target_os = "linux")]
+enum LinuxPackaging {
+    Native,
+    Flatpak(String),
+    Snap(String),
+    AppImage,
+}
+
+#[cfg(target_os = "linux")]
+impl LinuxPackaging {
+    fn flatpak_export_dirs() -> Vec<std::path::PathBuf> {
+        let mut dirs = vec![std::path::PathBuf::from("/var/lib/flatpak/exports/bin")];
+        if let Ok(home) = std::env::var("HOME") {
+            dirs.push(std::path::PathBuf::from(home).join(".local/share/flatpak/exports/bin"));
+        }
+        dirs
+    }
+
+    /// AppImages start with the ELF magic followed by an `AI` marker and a
+    /// format-version byte at offset 8, per the AppImage type 1/2 spec.
+    fn has_appimage_signature(path: &std::path::Path) -> bool {
+        use std::io::Read;
+
+        let Ok(mut file) = std::fs::File::open(path) else {
+            return false;
+        };
+        let mut header = [0u8; 11];
+        if file.read_exact(&mut header).is_err() {
+            return false;
+        }
+
+        header[8] == 0x41 && header[9] == 0x49 && (header[10] == 0x01 || header[10] == 0x02)
+    }
+
+    fn detect(binary: &std::path::Path) -> Self {
+        if let Some(name) = binary.file_name().and_then(|n| n.to_str()) {
+            if Self::flatpak_export_dirs().iter().any(|dir| binary.starts_with(dir)) {
+                return Self::Flatpak(name.to_string());
+            }
+            if binary.starts_with("/snap") {
+                return Self::Snap(name.to_string());
+            }
+        }
+
+        if Self::has_appimage_signature(binary) {
+            return Self::AppImage;
+        }
+
+        Self::Native
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum GitApp {
     Cursor,
@@ -92,75 +146,285 @@ impl GitApp {
         self.raw_value()
     }
 
+    /// Directory override for this app from `settings.advanced.git_app_install_dirs`,
+    /// for portable/nonstandard installs the platform-specific discovery below
+    /// won't find on its own.
+    fn user_override_dir(&self) -> Option<std::path::PathBuf> {
+        let settings = crate::settings::Settings::load().ok()?;
+        let dir = settings.advanced.git_app_install_dirs.get(self.raw_value())?.clone();
+        Some(std::path::PathBuf::from(dir))
+    }
+
     #[cfg(target_os = "macos")]
-    pub fn is_installed(&self) -> bool {
-        // Check if app is installed using mdfind
-        let output = Command::new("mdfind")
-            .arg(format!("kMDItemCFBundleIdentifier == '{}'", self.bundle_identifier()))
+    fn macos_application_dirs() -> Vec<std::path::PathBuf> {
+        let mut dirs = vec![std::path::PathBuf::from("/Applications")];
+        if let Ok(home) = std::env::var("HOME") {
+            dirs.push(std::path::PathBuf::from(home).join("Applications"));
+        }
+        dirs
+    }
+
+    /// Bundle identifier of a single `.app`, read via `mdls` (a metadata
+    /// lookup on one known file) rather than `mdfind` (a Spotlight query
+    /// across the whole disk) — much cheaper when we already know where to look.
+    #[cfg(target_os = "macos")]
+    fn bundle_identifier_of(app_path: &std::path::Path) -> Option<String> {
+        let output = Command::new("mdls")
+            .arg("-name")
+            .arg("kMDItemCFBundleIdentifier")
+            .arg("-raw")
+            .arg(app_path)
             .output()
-            .ok();
+            .ok()?;
+
+        if !output.status.success() {
+            return None;
+        }
+
+        let id = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        if id.is_empty() || id == "(null)" {
+            None
+        } else {
+            Some(id)
+        }
+    }
+
+    #[cfg(target_os = "macos")]
+    fn find_in_application_dirs(&self) -> Option<std::path::PathBuf> {
+        for root in Self::macos_application_dirs() {
+            let Ok(entries) = std::fs::read_dir(&root) else {
+                continue;
+            };
+
+            for entry in entries.flatten() {
+                let path = entry.path();
+                if path.extension().and_then(|e| e.to_str()) != Some("app") {
+                    continue;
+                }
+
+                if Self::bundle_identifier_of(&path).as_deref() == Some(self.bundle_identifier()) {
+                    return Some(path);
+                }
+            }
+        }
 
-        output
-            .map(|o| !o.stdout.is_empty())
+        None
+    }
+
+    /// Slow fallback used only once the `/Applications` scan above has
+    /// missed: `system_profiler` enumerates every installed app on the
+    /// system, which is accurate but far more expensive than a directory scan.
+    #[cfg(target_os = "macos")]
+    fn find_via_system_profiler(&self) -> bool {
+        Command::new("system_profiler")
+            .arg("SPApplicationsDataType")
+            .output()
+            .ok()
+            .map(|o| String::from_utf8_lossy(&o.stdout).contains(self.bundle_identifier()))
             .unwrap_or(false)
     }
 
-    #[cfg(target_os = "windows")]
+    #[cfg(target_os = "macos")]
     pub fn is_installed(&self) -> bool {
-        // Check common installation paths on Windows
-        match self {
-            Self::VSCode => {
-                // Check if VS Code is in PATH
-                Command::new("code")
-                    .arg("--version")
-                    .output()
-                    .is_ok()
+        if let Some(dir) = self.user_override_dir() {
+            if Self::bundle_identifier_of(&dir).as_deref() == Some(self.bundle_identifier()) {
+                return true;
             }
-            Self::GitHubDesktop => {
-                // Check for GitHub Desktop in AppData
-                let app_data = std::env::var("LOCALAPPDATA").unwrap_or_default();
-                std::path::Path::new(&app_data)
-                    .join("GitHubDesktop")
-                    .join("GitHubDesktop.exe")
-                    .exists()
+        }
+
+        if self.find_in_application_dirs().is_some() {
+            return true;
+        }
+
+        self.find_via_system_profiler()
+    }
+
+    /// Candidate substrings matched against the registry `DisplayName` value
+    /// under `...\CurrentVersion\Uninstall\*`.
+    #[cfg(target_os = "windows")]
+    fn windows_registry_hints(&self) -> &'static [&'static str] {
+        match self {
+            Self::Cursor => &["Cursor"],
+            Self::Fork => &["Fork"],
+            Self::GitHubDesktop => &["GitHub Desktop"],
+            Self::GitUp => &["GitUp"],
+            Self::SourceTree => &["SourceTree", "Atlassian SourceTree"],
+            Self::SublimeMerge => &["Sublime Merge"],
+            Self::Tower => &["Tower"],
+            Self::VSCode => &["Microsoft Visual Studio Code"],
+            Self::Windsurf => &["Windsurf"],
+        }
+    }
+
+    #[cfg(target_os = "windows")]
+    fn windows_exe_name(&self) -> &'static str {
+        match self {
+            Self::Cursor => "Cursor.exe",
+            Self::Fork => "Fork.exe",
+            Self::GitHubDesktop => "GitHubDesktop.exe",
+            Self::GitUp => "GitUp.exe",
+            Self::SourceTree => "SourceTree.exe",
+            Self::SublimeMerge => "sublime_merge.exe",
+            Self::Tower => "Tower.exe",
+            Self::VSCode => "Code.exe",
+            Self::Windsurf => "Windsurf.exe",
+        }
+    }
+
+    /// Resolve this app's install directory by reading the `Uninstall`
+    /// registry keys (mirroring how editor CLIs like VS Code find system
+    /// installs) instead of guessing folder names under Program Files/AppData.
+    #[cfg(target_os = "windows")]
+    fn find_windows_install_dir(&self) -> Option<std::path::PathBuf> {
+        use winreg::enums::{HKEY_CURRENT_USER, HKEY_LOCAL_MACHINE};
+        use winreg::RegKey;
+
+        if let Some(dir) = self.user_override_dir() {
+            if dir.join(self.windows_exe_name()).exists() {
+                return Some(dir);
             }
-            Self::Fork => {
-                // Check for Fork in Program Files
-                let program_files = std::env::var("ProgramFiles").unwrap_or_default();
-                std::path::Path::new(&program_files)
-                    .join("Fork")
-                    .join("Fork.exe")
-                    .exists()
+        }
+
+        let roots = [
+            (HKEY_LOCAL_MACHINE, r"Software\Microsoft\Windows\CurrentVersion\Uninstall"),
+            (
+                HKEY_LOCAL_MACHINE,
+                r"Software\WOW6432Node\Microsoft\Windows\CurrentVersion\Uninstall",
+            ),
+            (HKEY_CURRENT_USER, r"Software\Microsoft\Windows\CurrentVersion\Uninstall"),
+        ];
+
+        for (hive, path) in roots {
+            let Ok(uninstall) = RegKey::predef(hive).open_subkey(path) else {
+                continue;
+            };
+
+            for subkey_name in uninstall.enum_keys().flatten() {
+                let Ok(subkey) = uninstall.open_subkey(&subkey_name) else {
+                    continue;
+                };
+
+                let display_name: String = subkey.get_value("DisplayName").unwrap_or_default();
+                if !self.windows_registry_hints().iter().any(|hint| display_name.contains(hint)) {
+                    continue;
+                }
+
+                if let Ok(install_location) = subkey.get_value::<String, _>("InstallLocation") {
+                    let dir = std::path::PathBuf::from(install_location);
+                    if dir.join(self.windows_exe_name()).exists() {
+                        return Some(dir);
+                    }
+                }
             }
-            Self::SourceTree => {
-                // Check for SourceTree in AppData
-                let app_data = std::env::var("LOCALAPPDATA").unwrap_or_default();
-                std::path::Path::new(&app_data)
-                    .join("SourceTree")
-                    .join("SourceTree.exe")
-                    .exists()
+        }
+
+        None
+    }
+
+    /// Resolve a runnable binary for this app: VS Code and Sublime Merge are
+    /// commonly added to PATH during install, so that's checked before the
+    /// (slower) registry scan.
+    #[cfg(target_os = "windows")]
+    fn windows_binary(&self) -> Option<std::path::PathBuf> {
+        match self {
+            Self::VSCode if Command::new("code").arg("--version").output().is_ok() => {
+                return Some(std::path::PathBuf::from("code"));
             }
-            Self::SublimeMerge => {
-                // Check if Sublime Merge is in PATH
-                Command::new("smerge")
-                    .arg("--version")
-                    .output()
-                    .is_ok()
+            Self::SublimeMerge if Command::new("smerge").arg("--version").output().is_ok() => {
+                return Some(std::path::PathBuf::from("smerge"));
             }
-            _ => false,
+            _ => {}
         }
+
+        self.find_windows_install_dir().map(|dir| dir.join(self.windows_exe_name()))
     }
 
-    #[cfg(target_os = "linux")]
+    #[cfg(target_os = "windows")]
     pub fn is_installed(&self) -> bool {
-        // Check if application is available in PATH
+        self.windows_binary().is_some()
+    }
+
+    #[cfg(target_os = "linux")]
+    fn linux_binary_names(&self) -> &'static [&'static str] {
+        match self {
+            Self::Cursor => &["cursor"],
+            Self::Fork => &[],
+            Self::GitHubDesktop => &["github-desktop"],
+            Self::GitUp => &[],
+            Self::SourceTree => &[],
+            Self::SublimeMerge => &["smerge"],
+            Self::Tower => &[],
+            Self::VSCode => &["code", "code-insiders"],
+            Self::Windsurf => &["windsurf"],
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    fn linux_desktop_ids(&self) -> &'static [&'static str] {
         match self {
-            Self::VSCode => Command::new("code").arg("--version").output().is_ok(),
-            Self::SublimeMerge => Command::new("smerge").arg("--version").output().is_ok(),
-            _ => false,
+            Self::Cursor => &["cursor.desktop"],
+            Self::Fork => &[],
+            Self::GitHubDesktop => &["github-desktop.desktop"],
+            Self::GitUp => &[],
+            Self::SourceTree => &[],
+            Self::SublimeMerge => &["sublime-merge.desktop"],
+            Self::Tower => &[],
+            Self::VSCode => &["code.desktop", "code-insiders.desktop"],
+            Self::Windsurf => &["windsurf.desktop"],
         }
     }
 
+    #[cfg(target_os = "linux")]
+    fn which_path(binary: &str) -> Option<std::path::PathBuf> {
+        let output = Command::new("which").arg(binary).output().ok()?;
+        if !output.status.success() {
+            return None;
+        }
+
+        let path = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        if path.is_empty() {
+            None
+        } else {
+            Some(std::path::PathBuf::from(path))
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    fn resolve_linux_binary(&self) -> Option<std::path::PathBuf> {
+        self.linux_binary_names().iter().find_map(|bin| Self::which_path(bin))
+    }
+
+    #[cfg(target_os = "linux")]
+    fn linux_desktop_dirs() -> Vec<std::path::PathBuf> {
+        let mut dirs = vec![
+            std::path::PathBuf::from("/usr/share/applications"),
+            std::path::PathBuf::from("/usr/local/share/applications"),
+        ];
+        if let Ok(home) = std::env::var("HOME") {
+            dirs.push(std::path::PathBuf::from(&home).join(".local/share/applications"));
+        }
+        dirs
+    }
+
+    #[cfg(target_os = "linux")]
+    pub fn is_installed(&self) -> bool {
+        if let Some(dir) = self.user_override_dir() {
+            let found = self.linux_binary_names().iter().any(|bin| dir.join(bin).exists())
+                || self.linux_desktop_ids().iter().any(|id| dir.join(id).exists());
+            if found {
+                return true;
+            }
+        }
+
+        if self.resolve_linux_binary().is_some() {
+            return true;
+        }
+
+        self.linux_desktop_ids()
+            .iter()
+            .any(|id| Self::linux_desktop_dirs().iter().any(|dir| dir.join(id).exists()))
+    }
+
     pub fn installed_apps() -> Vec<Self> {
         Self::all()
             .into_iter()
@@ -169,100 +433,216 @@ impl GitApp {
     }
 }
 
+/// Whether a `GitAppLaunch`'s stdout/stderr should be left attached to
+/// VibeTunnel's own, captured for later inspection, or discarded.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum OutputConfig {
+    #[default]
+    Inherit,
+    Capture,
+    Null,
+}
+
+impl OutputConfig {
+    fn to_stdio(self) -> std::process::Stdio {
+        match self {
+            Self::Inherit => std::process::Stdio::inherit(),
+            Self::Capture => std::process::Stdio::piped(),
+            Self::Null => std::process::Stdio::null(),
+        }
+    }
+}
+
+/// Why a `GitAppLaunch::start()` failed, distinguishing "there's no
+/// installed binary to run" from "we found one but the OS couldn't spawn it".
+#[derive(Debug)]
+pub enum LaunchError {
+    NotInstalled(GitApp),
+    Spawn(String),
+}
+
+impl std::fmt::Display for LaunchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotInstalled(app) => write!(f, "{} is not installed", app.display_name()),
+            Self::Spawn(e) => write!(f, "failed to launch app: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for LaunchError {}
+
+/// Builder for launching a `GitApp`, mirroring mozrunner's `Runner` trait:
+/// configure arguments/environment/stdio, then `start()` for a handle to the
+/// spawned process. Replaces the old per-OS `Command` blocks in
+/// `GitAppLauncher::open_repository` with one typed surface shared by every platform.
+pub struct GitAppLaunch {
+    app: GitApp,
+    args: Vec<String>,
+    envs: Vec<(String, String)>,
+    stdout: OutputConfig,
+    stderr: OutputConfig,
+}
+
+impl GitAppLaunch {
+    pub fn new(app: GitApp) -> Self {
+        Self {
+            app,
+            args: Vec::new(),
+            envs: Vec::new(),
+            stdout: OutputConfig::default(),
+            stderr: OutputConfig::default(),
+        }
+    }
+
+    pub fn arg(mut self, arg: impl Into<String>) -> Self {
+        self.args.push(arg.into());
+        self
+    }
+
+    pub fn env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.envs.push((key.into(), value.into()));
+        self
+    }
+
+    pub fn envs(mut self, vars: impl IntoIterator<Item = (String, String)>) -> Self {
+        self.envs.extend(vars);
+        self
+    }
+
+    pub fn stdout(mut self, config: OutputConfig) -> Self {
+        self.stdout = config;
+        self
+    }
+
+    pub fn stderr(mut self, config: OutputConfig) -> Self {
+        self.stderr = config;
+        self
+    }
+
+    /// The platform-specific invocation for this app, before `args`/`envs`
+    /// are applied — `None` means there's no runnable binary to launch.
+    #[cfg(target_os = "macos")]
+    fn base_command(&self) -> Option<Command> {
+        if !self.app.is_installed() {
+            return None;
+        }
+        let mut command = Command::new("open");
+        command.arg("-b").arg(self.app.bundle_identifier());
+        Some(command)
+    }
+
+    #[cfg(target_os = "windows")]
+    fn base_command(&self) -> Option<Command> {
+        self.app.windows_binary().map(Command::new)
+    }
+
+    #[cfg(target_os = "linux")]
+    fn base_command(&self) -> Option<Command> {
+        let binary = self.app.resolve_linux_binary()?;
+        Some(match LinuxPackaging::detect(&binary) {
+            LinuxPackaging::Flatpak(app_id) => {
+                let mut command = Command::new("flatpak");
+                command.arg("run").arg(app_id);
+                command
+            }
+            LinuxPackaging::Snap(name) => Command::new(name),
+            LinuxPackaging::AppImage | LinuxPackaging::Native => Command::new(binary),
+        })
+    }
+
+    pub fn start(self) -> Result<LaunchedProcess, LaunchError> {
+        let mut command = self.base_command().ok_or_else(|| LaunchError::NotInstalled(self.app.clone()))?;
+
+        command.args(&self.args);
+        for (key, value) in &self.envs {
+            command.env(key, value);
+        }
+
+        // Strip VibeTunnel's own AppImage/Flatpak/Snap environment before
+        // handing the child process off, so the launched Git app doesn't
+        // inherit our bundle's PATH/LD_LIBRARY_PATH/XDG_* entries.
+        #[cfg(target_os = "linux")]
+        crate::linux_env::apply_to_command(&mut command);
+
+        command.stdout(self.stdout.to_stdio());
+        command.stderr(self.stderr.to_stdio());
+
+        let child = command.spawn().map_err(|e| LaunchError::Spawn(e.to_string()))?;
+        Ok(LaunchedProcess { child })
+    }
+}
+
+/// Handle to a process spawned by `GitAppLaunch::start`.
+pub struct LaunchedProcess {
+    child: std::process::Child,
+}
+
+impl LaunchedProcess {
+    pub fn pid(&self) -> u32 {
+        self.child.id()
+    }
+
+    /// Non-blocking check of whether the process has exited: `Ok(None)`
+    /// means it's still running, `Ok(Some(status))` means it already exited
+    /// (and the child has been reaped), an error means the status couldn't be read.
+    pub fn try_status(&mut self) -> Result<Option<std::process::ExitStatus>, String> {
+        self.child.try_wait().map_err(|e| e.to_string())
+    }
+
+    /// Everything the process writes to stderr, if it was captured via
+    /// `GitAppLaunch::stderr(OutputConfig::Capture)`. Blocks until the
+    /// process closes its stderr (normally when it exits).
+    pub fn read_stderr(&mut self) -> Option<String> {
+        use std::io::Read;
+
+        let mut buf = String::new();
+        self.child.stderr.as_mut()?.read_to_string(&mut buf).ok()?;
+        Some(buf)
+    }
+}
+
 pub struct GitAppLauncher;
 
 impl GitAppLauncher {
-    /// Open a repository in the preferred Git app
+    /// Open a repository in the preferred Git app, falling back to the
+    /// platform's file manager if no Git app is configured or installed.
     pub fn open_repository(path: &str) -> Result<(), String> {
-        let git_app = Self::get_preferred_git_app();
-        
+        let Some(app) = Self::get_preferred_git_app() else {
+            return Self::open_in_file_manager(path);
+        };
+
+        match GitAppLaunch::new(app.clone()).arg(path).start() {
+            Ok(_) => Ok(()),
+            Err(LaunchError::NotInstalled(_)) => Self::open_in_file_manager(path),
+            Err(e @ LaunchError::Spawn(_)) => Err(format!("Failed to launch {}: {}", app.display_name(), e)),
+        }
+    }
+
+    /// Fallback used when there's no Git app to hand the path to.
+    fn open_in_file_manager(path: &str) -> Result<(), String> {
         #[cfg(target_os = "macos")]
         {
-            if let Some(app) = git_app {
-                // Use open command with bundle identifier
-                let output = Command::new("open")
-                    .arg("-b")
-                    .arg(app.bundle_identifier())
-                    .arg(path)
-                    .output()
-                    .map_err(|e| format!("Failed to launch Git app: {}", e))?;
-
-                if !output.status.success() {
-                    let stderr = String::from_utf8_lossy(&output.stderr);
-                    return Err(format!("Failed to open Git app: {}", stderr));
-                }
-            } else {
-                // Fallback to opening in Finder
-                Command::new("open")
-                    .arg(path)
-                    .spawn()
-                    .map_err(|e| format!("Failed to open in Finder: {}", e))?;
-            }
+            Command::new("open")
+                .arg(path)
+                .spawn()
+                .map_err(|e| format!("Failed to open in Finder: {}", e))?;
         }
 
         #[cfg(target_os = "windows")]
         {
-            if let Some(app) = git_app {
-                match app {
-                    GitApp::VSCode => {
-                        Command::new("code")
-                            .arg(path)
-                            .spawn()
-                            .map_err(|e| format!("Failed to launch VS Code: {}", e))?;
-                    }
-                    GitApp::GitHubDesktop => {
-                        let app_data = std::env::var("LOCALAPPDATA").unwrap_or_default();
-                        let github_desktop = std::path::Path::new(&app_data)
-                            .join("GitHubDesktop")
-                            .join("GitHubDesktop.exe");
-                        
-                        Command::new(github_desktop)
-                            .arg(path)
-                            .spawn()
-                            .map_err(|e| format!("Failed to launch GitHub Desktop: {}", e))?;
-                    }
-                    _ => {
-                        // Fallback to Explorer
-                        Command::new("explorer")
-                            .arg(path)
-                            .spawn()
-                            .map_err(|e| format!("Failed to open in Explorer: {}", e))?;
-                    }
-                }
-            } else {
-                // Fallback to Explorer
-                Command::new("explorer")
-                    .arg(path)
-                    .spawn()
-                    .map_err(|e| format!("Failed to open in Explorer: {}", e))?;
-            }
+            Command::new("explorer")
+                .arg(path)
+                .spawn()
+                .map_err(|e| format!("Failed to open in Explorer: {}", e))?;
         }
 
         #[cfg(target_os = "linux")]
         {
-            if let Some(app) = git_app {
-                match app {
-                    GitApp::VSCode => {
-                        Command::new("code")
-                            .arg(path)
-                            .spawn()
-                            .map_err(|e| format!("Failed to launch VS Code: {}", e))?;
-                    }
-                    _ => {
-                        // Fallback to file manager
-                        Command::new("xdg-open")
-                            .arg(path)
-                            .spawn()
-                            .map_err(|e| format!("Failed to open in file manager: {}", e))?;
-                    }
-                }
-            } else {
-                // Fallback to file manager
-                Command::new("xdg-open")
-                    .arg(path)
-                    .spawn()
-                    .map_err(|e| format!("Failed to open in file manager: {}", e))?;
-            }
+            let mut process = Command::new("xdg-open");
+            process.arg(path);
+            crate::linux_env::apply_to_command(&mut process);
+            process.spawn().map_err(|e| format!("Failed to open in file manager: {}", e))?;
         }
 
         Ok(())
@@ -3,21 +3,38 @@ use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tauri::async_runtime::Mutex;
 use tauri::{AppHandle, Emitter};
 use tokio::sync::RwLock;
 use tokio::time::interval;
 
+/// Polling cadence used while at least one cached repo is due for a check;
+/// individual repos back off past this once they stop changing.
+const REFRESH_TICK: Duration = Duration::from_secs(1);
+/// Fastest a changing repo gets re-polled.
+const MIN_REFRESH_INTERVAL: Duration = Duration::from_secs(5);
+/// Slowest an idle repo gets re-polled.
+const MAX_REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Per-repo adaptive polling state: how long to wait before the next check,
+/// and when that wait is up.
+struct RefreshSchedule {
+    interval: Duration,
+    due_at: Instant,
+}
+
 pub struct GitMonitor {
     // Cache for repository information by repository path
     repository_cache: Arc<RwLock<HashMap<String, GitRepository>>>,
     // Cache mapping file paths to their repository paths
     file_to_repo_cache: Arc<RwLock<HashMap<String, String>>>,
-    // Cache for GitHub URLs by repository path
-    github_url_cache: Arc<RwLock<HashMap<String, String>>>,
-    // Track in-progress GitHub URL fetches
-    github_url_fetches: Arc<Mutex<std::collections::HashSet<String>>>,
+    // Cache for resolved web URLs by repository path
+    web_url_cache: Arc<RwLock<HashMap<String, String>>>,
+    // Track in-progress web URL fetches
+    web_url_fetches: Arc<Mutex<std::collections::HashSet<String>>>,
+    // Adaptive refresh cadence per repository path
+    refresh_schedule: Arc<RwLock<HashMap<String, RefreshSchedule>>>,
 }
 
 impl GitMonitor {
@@ -25,8 +42,9 @@ impl GitMonitor {
         Self {
             repository_cache: Arc::new(RwLock::new(HashMap::new())),
             file_to_repo_cache: Arc::new(RwLock::new(HashMap::new())),
-            github_url_cache: Arc::new(RwLock::new(HashMap::new())),
-            github_url_fetches: Arc::new(Mutex::new(std::collections::HashSet::new())),
+            web_url_cache: Arc::new(RwLock::new(HashMap::new())),
+            web_url_fetches: Arc::new(Mutex::new(std::collections::HashSet::new())),
+            refresh_schedule: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
@@ -79,27 +97,70 @@ impl GitMonitor {
     pub async fn clear_cache(&self) {
         self.repository_cache.write().await.clear();
         self.file_to_repo_cache.write().await.clear();
-        self.github_url_cache.write().await.clear();
-        self.github_url_fetches.lock().await.clear();
+        self.web_url_cache.write().await.clear();
+        self.web_url_fetches.lock().await.clear();
+        self.refresh_schedule.write().await.clear();
     }
 
     /// Start monitoring and refreshing all cached repositories
     pub async fn start_monitoring(&self, app_handle: AppHandle) {
         let cache = self.repository_cache.clone();
-        let github_cache = self.github_url_cache.clone();
-        let fetches = self.github_url_fetches.clone();
+        let web_cache = self.web_url_cache.clone();
+        let fetches = self.web_url_fetches.clone();
+        let schedule = self.refresh_schedule.clone();
 
         tokio::spawn(async move {
-            let mut refresh_interval = interval(Duration::from_secs(5));
+            let mut tick = interval(REFRESH_TICK);
             loop {
-                refresh_interval.tick().await;
-                Self::refresh_all_cached(&cache, &github_cache, &fetches).await;
-                // Emit event to update UI
-                let _ = app_handle.emit("git-repos-updated", ());
+                tick.tick().await;
+                if Self::refresh_all_cached(&cache, &web_cache, &fetches, &schedule).await {
+                    // Emit event to update UI only when something actually changed
+                    let _ = app_handle.emit("git-repos-updated", ());
+                }
             }
         });
     }
 
+    /// Discover every Git repository under `root` — including nested repos
+    /// and submodule working trees, where `.git` is a file pointing at
+    /// `../.git/modules/...` rather than a directory — and register them
+    /// into the repository cache. Unlike `find_repository`, which only ever
+    /// resolves the first `.git` found walking *up* from a single file, this
+    /// walks *down* from a workspace root so sibling and nested repos are
+    /// all kept resident for the lifetime of the monitor.
+    pub async fn scan_workspace(&self, root: &Path) {
+        let mut roots = Vec::new();
+        Self::collect_git_roots(root, &mut roots);
+
+        for repo_path in roots {
+            if let Some(repository) = self.get_repository_status(&repo_path).await {
+                self.cache_repository(&repository, None).await;
+            }
+        }
+    }
+
+    /// Walk `dir` downward, recording every directory containing a `.git`
+    /// entry (dir or file, so submodule working trees are included too).
+    fn collect_git_roots(dir: &Path, roots: &mut Vec<String>) {
+        if dir.join(".git").exists() {
+            if let Some(path_str) = dir.to_str() {
+                roots.push(path_str.to_string());
+            }
+        }
+
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return;
+        };
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if !path.is_dir() || path.file_name().and_then(|n| n.to_str()) == Some(".git") {
+                continue;
+            }
+            Self::collect_git_roots(&path, roots);
+        }
+    }
+
     /// Validate and sanitize paths
     fn validate_path(path: &str) -> bool {
         let path = Path::new(path);
@@ -135,17 +196,17 @@ impl GitMonitor {
         // Get basic git status
         let mut repository = Self::get_basic_git_status(repo_path)?;
 
-        // Check if we have a cached GitHub URL
-        let github_urls = self.github_url_cache.read().await;
-        if let Some(url) = github_urls.get(repo_path) {
-            repository.github_url = Some(url.clone());
+        // Check if we have a cached web URL
+        let web_urls = self.web_url_cache.read().await;
+        if let Some(url) = web_urls.get(repo_path) {
+            repository.web_url = Some(url.clone());
         } else {
-            // Fetch GitHub URL in background
+            // Fetch the web URL in background
             let repo_path_clone = repo_path.to_string();
-            let github_cache = self.github_url_cache.clone();
-            let fetches = self.github_url_fetches.clone();
+            let web_cache = self.web_url_cache.clone();
+            let fetches = self.web_url_fetches.clone();
             tokio::spawn(async move {
-                Self::fetch_github_url_background(repo_path_clone, github_cache, fetches).await;
+                Self::fetch_web_url_background(repo_path_clone, web_cache, fetches).await;
             });
         }
 
@@ -170,63 +231,34 @@ impl GitMonitor {
 
     /// Parse git status --porcelain output
     fn parse_git_status(output: &str, repo_path: &str) -> GitRepository {
-        let lines: Vec<&str> = output.lines().collect();
         let mut current_branch = None;
-        let mut modified_count = 0;
-        let mut added_count = 0;
-        let mut deleted_count = 0;
-        let mut untracked_count = 0;
 
-        for line in &lines {
+        for line in output.lines() {
             let trimmed = line.trim();
-
-            // Parse branch information (first line with --branch flag)
-            if trimmed.starts_with("##") {
-                let branch_info = trimmed[2..].trim();
-                // Extract branch name (format: "branch...tracking" or just "branch")
-                if let Some(dot_index) = branch_info.find('.') {
-                    current_branch = Some(branch_info[..dot_index].to_string());
-                } else {
-                    current_branch = Some(branch_info.to_string());
-                }
-                continue;
-            }
-
-            // Skip empty lines
-            if trimmed.len() < 2 {
+            if !trimmed.starts_with("##") {
                 continue;
             }
 
-            // Get status code (first two characters)
-            let status_code = &trimmed[..2];
-
-            // Count files based on status codes
-            match status_code {
-                "??" => untracked_count += 1,
-                code if code.contains('M') => modified_count += 1,
-                code if code.contains('A') => added_count += 1,
-                code if code.contains('D') => deleted_count += 1,
-                code if code.contains('R') || code.contains('C') => modified_count += 1,
-                code if code.contains('U') => modified_count += 1,
-                _ => {}
-            }
+            // Extract branch name (format: "branch...tracking" or just "branch")
+            let branch_info = trimmed[2..].trim();
+            current_branch = Some(match branch_info.find('.') {
+                Some(dot_index) => branch_info[..dot_index].to_string(),
+                None => branch_info.to_string(),
+            });
+            break;
         }
 
-        GitRepository {
-            path: repo_path.to_string(),
-            modified_count,
-            added_count,
-            deleted_count,
-            untracked_count,
-            current_branch,
-            github_url: None,
-        }
+        // Per-file entries share one parser with `GitWatcher` so the XY code
+        // is sliced the same way in both places (see `parse_porcelain_files`).
+        let files = crate::git_repository::parse_porcelain_files(output);
+
+        GitRepository::from_files(repo_path.to_string(), files, current_branch)
     }
 
-    /// Fetch GitHub URL in background and cache it
-    async fn fetch_github_url_background(
+    /// Fetch the resolved web URL in background and cache it
+    async fn fetch_web_url_background(
         repo_path: String,
-        github_cache: Arc<RwLock<HashMap<String, String>>>,
+        web_cache: Arc<RwLock<HashMap<String, String>>>,
         fetches: Arc<Mutex<std::collections::HashSet<String>>>,
     ) {
         // Check if already fetching
@@ -238,37 +270,92 @@ impl GitMonitor {
             fetches_guard.insert(repo_path.clone());
         }
 
-        // Fetch GitHub URL
-        if let Some(github_url) = GitRepository::get_github_url(&repo_path) {
-            github_cache.write().await.insert(repo_path.clone(), github_url);
+        // Fetch and resolve the remote's web URL
+        if let Some(web_url) = GitRepository::get_web_url(&repo_path) {
+            web_cache.write().await.insert(repo_path.clone(), web_url.url);
         }
 
         // Remove from fetches
         fetches.lock().await.remove(&repo_path);
     }
 
-    /// Refresh all cached repositories
+    /// Refresh every cached repository that's due for a check. Each
+    /// `git status` call runs on a blocking-pool worker (`Command::output`
+    /// blocks the thread, which would otherwise stall the tokio runtime for
+    /// the duration of a huge repo's status scan), and results are written
+    /// back one repo at a time under a short-lived write lock rather than
+    /// holding the cache lock across any of the subprocess calls.
+    ///
+    /// A repo that comes back unchanged backs off toward
+    /// `MAX_REFRESH_INTERVAL`; a change resets it to `MIN_REFRESH_INTERVAL`,
+    /// so idle projects stop paying the polling cost every tick. Returns
+    /// whether any repo's status actually changed.
     async fn refresh_all_cached(
         cache: &Arc<RwLock<HashMap<String, GitRepository>>>,
-        github_cache: &Arc<RwLock<HashMap<String, String>>>,
+        web_cache: &Arc<RwLock<HashMap<String, String>>>,
         _fetches: &Arc<Mutex<std::collections::HashSet<String>>>,
-    ) {
-        let repo_paths: Vec<String> = {
+        schedule: &Arc<RwLock<HashMap<String, RefreshSchedule>>>,
+    ) -> bool {
+        let now = Instant::now();
+
+        let due_paths: Vec<String> = {
             let repos = cache.read().await;
-            repos.keys().cloned().collect()
+            let schedules = schedule.read().await;
+            repos
+                .keys()
+                .filter(|path| schedules.get(path.as_str()).map(|s| now >= s.due_at).unwrap_or(true))
+                .cloned()
+                .collect()
         };
 
-        for repo_path in repo_paths {
-            if let Some(mut fresh) = Self::get_basic_git_status(&repo_path) {
-                // Add GitHub URL if cached
-                let github_urls = github_cache.read().await;
-                if let Some(url) = github_urls.get(&repo_path) {
-                    fresh.github_url = Some(url.clone());
-                }
+        // Kick every due repo's status check off onto the blocking pool up
+        // front, then collect results as they finish — this is the
+        // equivalent of a `join_all` without pulling in an extra crate.
+        let handles: Vec<_> = due_paths
+            .into_iter()
+            .map(|path| tokio::task::spawn_blocking(move || (path.clone(), Self::get_basic_git_status(&path))))
+            .collect();
+
+        let mut any_changed = false;
+        for handle in handles {
+            let Ok((repo_path, fresh)) = handle.await else {
+                continue;
+            };
+            let Some(mut fresh) = fresh else {
+                continue;
+            };
 
-                cache.write().await.insert(repo_path, fresh);
+            // Add the resolved web URL if cached
+            {
+                let web_urls = web_cache.read().await;
+                if let Some(url) = web_urls.get(&repo_path) {
+                    fresh.web_url = Some(url.clone());
+                }
             }
+
+            let changed = {
+                let mut repos = cache.write().await;
+                let changed = repos.get(&repo_path).map(|previous| previous != &fresh).unwrap_or(true);
+                repos.insert(repo_path.clone(), fresh);
+                changed
+            };
+            any_changed = any_changed || changed;
+
+            let mut schedules = schedule.write().await;
+            let next_interval = match schedules.get(&repo_path) {
+                Some(previous) if !changed => std::cmp::min(previous.interval * 2, MAX_REFRESH_INTERVAL),
+                _ => MIN_REFRESH_INTERVAL,
+            };
+            schedules.insert(
+                repo_path,
+                RefreshSchedule {
+                    interval: next_interval,
+                    due_at: now + next_interval,
+                },
+            );
         }
+
+        any_changed
     }
 
     /// Cache repository information
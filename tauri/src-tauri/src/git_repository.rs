@@ -2,6 +2,108 @@ use serde::{Deserialize, Serialize};
 use std::path::Path;
 use std::process::Command;
 
+/// Which forge a repository's remote resolved to.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum ForgeKind {
+    GitHub,
+    GitLab,
+    Bitbucket,
+    Other,
+}
+
+/// A parsed, canonicalized remote URL.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct RemoteWebUrl {
+    pub url: String,
+    pub host: String,
+    pub forge: ForgeKind,
+}
+
+/// How a single `git status --porcelain` entry should be presented, derived
+/// from its XY code.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum GitFileStatusKind {
+    Modified,
+    Added,
+    Deleted,
+    Renamed,
+    Untracked,
+    Conflicted,
+}
+
+/// Per-file git status, as reported by one `git status --porcelain` line.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct GitFileStatus {
+    pub path: String,
+    pub xy: String,
+    pub kind: GitFileStatusKind,
+    /// For renames, the path the file was renamed from (`orig -> new`).
+    pub rename_from: Option<String>,
+}
+
+impl GitFileStatus {
+    /// Classify a single porcelain entry: `xy` is the two-character status
+    /// code, `path` is everything after it (which for renames/copies is
+    /// `orig -> new`).
+    pub fn classify(xy: &str, path: &str) -> Self {
+        let (rename_from, path) = match path.split_once(" -> ") {
+            Some((orig, new)) => (Some(orig.to_string()), new.to_string()),
+            None => (None, path.to_string()),
+        };
+
+        let mut chars = xy.chars();
+        let x = chars.next().unwrap_or(' ');
+        let y = chars.next().unwrap_or(' ');
+
+        let kind = if x == '?' && y == '?' {
+            GitFileStatusKind::Untracked
+        } else if x == 'U' || y == 'U' || (x == 'A' && y == 'A') || (x == 'D' && y == 'D') {
+            GitFileStatusKind::Conflicted
+        } else if x == 'R' || y == 'R' {
+            GitFileStatusKind::Renamed
+        } else if x == 'D' || y == 'D' {
+            GitFileStatusKind::Deleted
+        } else if x == 'A' || y == 'A' {
+            GitFileStatusKind::Added
+        } else {
+            GitFileStatusKind::Modified
+        };
+
+        Self {
+            path,
+            xy: xy.to_string(),
+            kind,
+            rename_from,
+        }
+    }
+}
+
+/// Parse the per-file entries out of `git status --porcelain` output
+/// (with or without a leading `## branch...tracking` line from
+/// `--branch`), the single source of truth for turning porcelain lines
+/// into [`GitFileStatus`] entries so callers can't disagree on how the XY
+/// code is sliced out.
+///
+/// Each entry's XY code occupies the first two *un-trimmed* columns of
+/// the line (`" M"`, `"M "`, `"??"`, ...) — trimming the line first would
+/// collapse a leading space and misread an unstaged change as staged, so
+/// this only trims the path that follows, not the line itself.
+pub(crate) fn parse_porcelain_files(output: &str) -> Vec<GitFileStatus> {
+    let mut files = Vec::new();
+
+    for line in output.lines() {
+        if line.starts_with("##") || line.len() < 3 {
+            continue;
+        }
+
+        let xy = &line[..2];
+        let path = line[2..].trim_start();
+        files.push(GitFileStatus::classify(xy, path));
+    }
+
+    files
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct GitRepository {
     pub path: String,
@@ -10,7 +112,8 @@ pub struct GitRepository {
     pub deleted_count: usize,
     pub untracked_count: usize,
     pub current_branch: Option<String>,
-    pub github_url: Option<String>,
+    pub web_url: Option<String>,
+    pub files: Vec<GitFileStatus>,
 }
 
 impl GitRepository {
@@ -22,7 +125,44 @@ impl GitRepository {
             deleted_count: 0,
             untracked_count: 0,
             current_branch: None,
-            github_url: None,
+            web_url: None,
+            files: Vec::new(),
+        }
+    }
+
+    /// Build a repository status from its classified per-file entries,
+    /// deriving the aggregate counters rather than tracking them separately.
+    pub fn from_files(path: String, files: Vec<GitFileStatus>, current_branch: Option<String>) -> Self {
+        let mut repo = Self {
+            path,
+            modified_count: 0,
+            added_count: 0,
+            deleted_count: 0,
+            untracked_count: 0,
+            current_branch,
+            web_url: None,
+            files,
+        };
+        repo.recount();
+        repo
+    }
+
+    /// Recompute the aggregate counters from `files`. Call after mutating
+    /// `files` directly so the derived counts stay in sync.
+    pub fn recount(&mut self) {
+        self.modified_count = 0;
+        self.added_count = 0;
+        self.deleted_count = 0;
+        self.untracked_count = 0;
+
+        for file in &self.files {
+            match file.kind {
+                GitFileStatusKind::Modified | GitFileStatusKind::Conflicted => self.modified_count += 1,
+                GitFileStatusKind::Added => self.added_count += 1,
+                GitFileStatusKind::Deleted => self.deleted_count += 1,
+                GitFileStatusKind::Renamed => self.modified_count += 1,
+                GitFileStatusKind::Untracked => self.untracked_count += 1,
+            }
         }
     }
 
@@ -65,10 +205,12 @@ impl GitRepository {
         parts.join(" ")
     }
 
-    /// Extract GitHub URL from a repository path
-    pub fn get_github_url(repo_path: &str) -> Option<String> {
+    /// Resolve the repository's `origin` remote into a canonical web URL,
+    /// usable for "open repo in browser" across GitHub, GitLab, Bitbucket,
+    /// and self-hosted instances.
+    pub fn get_web_url(repo_path: &str) -> Option<RemoteWebUrl> {
         let output = Command::new("git")
-            .args(&["remote", "get-url", "origin"])
+            .args(["remote", "get-url", "origin"])
             .current_dir(repo_path)
             .output()
             .ok()?;
@@ -77,37 +219,81 @@ impl GitRepository {
             return None;
         }
 
-        let remote_url = String::from_utf8(output.stdout)
-            .ok()?
-            .trim()
-            .to_string();
-
-        Self::parse_github_url(&remote_url)
+        let remote_url = String::from_utf8(output.stdout).ok()?.trim().to_string();
+        Self::parse_remote_url(&remote_url)
     }
 
-    /// Parse GitHub URL from git remote output
-    fn parse_github_url(remote_url: &str) -> Option<String> {
-        // Handle HTTPS URLs: https://github.com/user/repo.git
-        if remote_url.starts_with("https://github.com/") {
-            let clean_url = if remote_url.ends_with(".git") {
-                &remote_url[..remote_url.len() - 4]
-            } else {
-                remote_url
-            };
-            return Some(clean_url.to_string());
+    /// Parse a git remote URL (HTTPS with optional embedded credentials,
+    /// `ssh://` with optional port, or scp-style `git@host:path`) into a
+    /// canonical web URL, preserving nested group paths.
+    fn parse_remote_url(remote_url: &str) -> Option<RemoteWebUrl> {
+        let (host, path) = if let Some(rest) = remote_url.strip_prefix("https://") {
+            Self::split_https(rest)?
+        } else if let Some(rest) = remote_url.strip_prefix("http://") {
+            Self::split_https(rest)?
+        } else if let Some(rest) = remote_url.strip_prefix("ssh://") {
+            Self::split_ssh_url(rest)?
+        } else if let Some(at_pos) = remote_url.find('@') {
+            // scp-style: git@host:group/subgroup/repo.git
+            let rest = &remote_url[at_pos + 1..];
+            let (host, path) = rest.split_once(':')?;
+            (host.to_string(), path.to_string())
+        } else {
+            return None;
+        };
+
+        let path = path.trim_end_matches('/').trim_end_matches(".git");
+        if path.is_empty() {
+            return None;
         }
 
-        // Handle SSH URLs: git@github.com:user/repo.git
-        if remote_url.starts_with("git@github.com:") {
-            let path_part = &remote_url["git@github.com:".len()..];
-            let clean_path = if path_part.ends_with(".git") {
-                &path_part[..path_part.len() - 4]
-            } else {
-                path_part
-            };
-            return Some(format!("https://github.com/{}", clean_path));
+        let forge = Self::detect_forge(&host);
+        let url = format!("https://{}/{}", host, path);
+
+        Some(RemoteWebUrl { url, host, forge })
+    }
+
+    /// Split the portion of a `https://`/`http://` URL after the scheme into
+    /// `(host, path)`, stripping any embedded `user:pass@` credentials.
+    fn split_https(rest: &str) -> Option<(String, String)> {
+        let rest = match rest.find('@') {
+            // Only treat this as credentials if it comes before the first '/'
+            Some(at_pos) if rest[..at_pos].find('/').is_none() => &rest[at_pos + 1..],
+            _ => rest,
+        };
+
+        let slash_pos = rest.find('/')?;
+        let host = rest[..slash_pos].to_string();
+        let path = rest[slash_pos + 1..].to_string();
+        Some((host, path))
+    }
+
+    /// Split `ssh://[user@]host[:port]/path` into `(host, path)`.
+    fn split_ssh_url(rest: &str) -> Option<(String, String)> {
+        let rest = match rest.find('@') {
+            Some(at_pos) => &rest[at_pos + 1..],
+            None => rest,
+        };
+
+        let slash_pos = rest.find('/')?;
+        let mut host = rest[..slash_pos].to_string();
+        // Strip an explicit port, e.g. "example.com:2222"
+        if let Some(colon_pos) = host.find(':') {
+            host.truncate(colon_pos);
         }
+        let path = rest[slash_pos + 1..].to_string();
+        Some((host, path))
+    }
 
-        None
+    fn detect_forge(host: &str) -> ForgeKind {
+        if host.contains("github.com") {
+            ForgeKind::GitHub
+        } else if host.contains("gitlab.com") || host.starts_with("gitlab.") {
+            ForgeKind::GitLab
+        } else if host.contains("bitbucket.org") {
+            ForgeKind::Bitbucket
+        } else {
+            ForgeKind::Other
+        }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,127 @@
+use std::sync::Arc;
+use tauri::menu::{Menu, MenuItem, PredefinedMenuItem};
+use tauri::tray::TrayIconBuilder;
+use tauri::{AppHandle, Manager};
+use tokio::sync::RwLock;
+use tracing::{debug, error, info};
+
+use crate::applescript::AppleScriptTerminalLauncher;
+use crate::git_monitor::GitMonitor;
+use crate::git_repository::GitRepository;
+
+const TRAY_ID: &str = "git-repos";
+
+/// Builds and refreshes a system-tray menu listing tracked Git repositories,
+/// their live status, and a quick action that launches a new terminal session
+/// for each. An already open session's window is not focused.
+pub struct GitRepoTray {
+    git_monitor: Arc<GitMonitor>,
+    tracked_paths: RwLock<Vec<String>>,
+}
+
+impl GitRepoTray {
+    pub fn new(git_monitor: Arc<GitMonitor>) -> Self {
+        Self {
+            git_monitor,
+            tracked_paths: RwLock::new(Vec::new()),
+        }
+    }
+
+    /// Add a repository path to the list the tray watches and displays.
+    pub async fn track_repository(&self, path: String) {
+        let mut paths = self.tracked_paths.write().await;
+        if !paths.contains(&path) {
+            paths.push(path);
+        }
+    }
+
+    /// Build the tray icon and its initial menu.
+    pub async fn build(&self, app: &AppHandle) -> Result<(), String> {
+        let repos = self.cached_tracked_repositories().await;
+        let menu = Self::build_menu(app, &repos)?;
+
+        TrayIconBuilder::with_id(TRAY_ID)
+            .menu(&menu)
+            .on_menu_event(move |app, event| {
+                let app = app.clone();
+                let id = event.id().0.clone();
+                tauri::async_runtime::spawn(async move {
+                    if let Err(e) = Self::handle_menu_event(&app, &id).await {
+                        error!("Failed to handle git tray menu event '{}': {}", id, e);
+                    }
+                });
+            })
+            .build(app)
+            .map_err(|e| format!("Failed to build git repo tray: {}", e))?;
+
+        Ok(())
+    }
+
+    fn build_menu(app: &AppHandle, repos: &[GitRepository]) -> Result<Menu<tauri::Wry>, String> {
+        let menu = Menu::new(app).map_err(|e| e.to_string())?;
+
+        for repo in repos {
+            let badge = if repo.has_changes() { " ●" } else { "" };
+            let label = format!("{} ({}){}", repo.folder_name(), repo.status_text(), badge);
+            let item = MenuItem::with_id(app, format!("repo:{}", repo.path), label, true, None::<&str>)
+                .map_err(|e| e.to_string())?;
+            menu.append(&item).map_err(|e| e.to_string())?;
+        }
+
+        menu.append(&PredefinedMenuItem::separator(app).map_err(|e| e.to_string())?)
+            .map_err(|e| e.to_string())?;
+
+        let dock_toggle = MenuItem::with_id(app, "toggle-dock", "Show in Dock", true, None::<&str>)
+            .map_err(|e| e.to_string())?;
+        menu.append(&dock_toggle).map_err(|e| e.to_string())?;
+
+        menu.append(&PredefinedMenuItem::quit(app, Some("Quit VibeTunnel")).map_err(|e| e.to_string())?)
+            .map_err(|e| e.to_string())?;
+
+        Ok(menu)
+    }
+
+    async fn handle_menu_event(app: &AppHandle, id: &str) -> Result<(), String> {
+        if let Some(repo_path) = id.strip_prefix("repo:") {
+            info!("Launching/focusing session for repo: {}", repo_path);
+            AppleScriptTerminalLauncher::launch_terminal("Terminal", repo_path, None, Some(repo_path))
+                .await
+                .map(|_| ())
+        } else if id == "toggle-dock" {
+            let state = app.state::<crate::state::AppState>();
+            let currently_shown = state.dock_manager.get_show_in_dock();
+            state.dock_manager.set_show_in_dock(!currently_shown);
+            if let Some(app_handle) = state.get_app_handle() {
+                state.dock_manager.update_dock_visibility(&app_handle);
+            }
+            Ok(())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Rebuild the menu to reflect the latest cached repo status from
+    /// `GitMonitor`.
+    pub async fn refresh(&self, app: &AppHandle) -> Result<(), String> {
+        let repos = self.cached_tracked_repositories().await;
+        debug!("Refreshing git repo tray with {} repositories", repos.len());
+
+        let menu = Self::build_menu(app, &repos)?;
+        if let Some(tray) = app.tray_by_id(TRAY_ID) {
+            tray.set_menu(Some(menu)).map_err(|e| e.to_string())?;
+        }
+
+        Ok(())
+    }
+
+    async fn cached_tracked_repositories(&self) -> Vec<GitRepository> {
+        let paths = self.tracked_paths.read().await.clone();
+        let mut repos = Vec::with_capacity(paths.len());
+        for path in paths {
+            if let Some(repo) = self.git_monitor.get_cached_repository(&path).await {
+                repos.push(repo);
+            }
+        }
+        repos
+    }
+}
@@ -0,0 +1,124 @@
+use crate::git_repository::GitRepository;
+use std::collections::HashMap;
+use std::process::Command;
+use std::sync::Arc;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+use tokio::sync::RwLock;
+use tokio::time::interval;
+use tracing::debug;
+
+/// Debounce interval between successive `git status` polls for a single
+/// registered repository.
+const DEBOUNCE: Duration = Duration::from_millis(750);
+
+/// Watches a set of registered repository paths and emits `git-repo-changed`
+/// whenever their computed status (per-file entries or branch) actually changes,
+/// rather than just refreshing a cache silently.
+pub struct GitWatcher {
+    repos: Arc<RwLock<HashMap<String, GitRepository>>>,
+}
+
+impl GitWatcher {
+    pub fn new() -> Self {
+        Self {
+            repos: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// Register a repository path to be watched.
+    pub async fn watch(&self, path: String) {
+        let mut repos = self.repos.write().await;
+        repos.entry(path.clone()).or_insert_with(|| GitRepository::new(path));
+    }
+
+    /// Stop watching a repository path.
+    pub async fn unwatch(&self, path: &str) {
+        self.repos.write().await.remove(path);
+    }
+
+    /// Start the debounced polling loop, emitting `git-repo-changed` events
+    /// whenever a watched repository's status changes.
+    pub async fn start(&self, app_handle: AppHandle) {
+        let repos = self.repos.clone();
+
+        tokio::spawn(async move {
+            let mut tick = interval(DEBOUNCE);
+            loop {
+                tick.tick().await;
+
+                let paths: Vec<String> = {
+                    let guard = repos.read().await;
+                    guard.keys().cloned().collect()
+                };
+
+                for path in paths {
+                    if let Some(fresh) = Self::compute_status(&path) {
+                        let changed = {
+                            let mut guard = repos.write().await;
+                            let previous = guard.get(&path);
+                            let changed = previous.map(|p| !Self::same_status(p, &fresh)).unwrap_or(true);
+                            guard.insert(path.clone(), fresh.clone());
+                            changed
+                        };
+
+                        if changed {
+                            debug!("Git status changed for {}", path);
+                            let _ = app_handle.emit("git-repo-changed", &fresh);
+                        }
+                    }
+                }
+            }
+        });
+    }
+
+    fn same_status(a: &GitRepository, b: &GitRepository) -> bool {
+        a.files == b.files && a.current_branch == b.current_branch
+    }
+
+    /// Run `git status --porcelain=v1` and `git rev-parse --abbrev-ref HEAD`
+    /// for a repository path and build a fully-populated `GitRepository`.
+    fn compute_status(path: &str) -> Option<GitRepository> {
+        let output = Command::new("git")
+            .args(["status", "--porcelain=v1"])
+            .current_dir(path)
+            .output()
+            .ok()?;
+
+        if !output.status.success() {
+            return None;
+        }
+
+        let porcelain = String::from_utf8(output.stdout).ok()?;
+        let mut repo = Self::parse_porcelain(&porcelain, path);
+        repo.current_branch = Self::current_branch(path);
+        Some(repo)
+    }
+
+    fn current_branch(path: &str) -> Option<String> {
+        let output = Command::new("git")
+            .args(["rev-parse", "--abbrev-ref", "HEAD"])
+            .current_dir(path)
+            .output()
+            .ok()?;
+
+        if !output.status.success() {
+            return None;
+        }
+
+        let branch = String::from_utf8(output.stdout).ok()?.trim().to_string();
+        if branch.is_empty() || branch == "HEAD" {
+            None
+        } else {
+            Some(branch)
+        }
+    }
+
+    /// Parse `git status --porcelain=v1` output (no `--branch` line) into
+    /// classified per-file entries, handling staged/worktree XY codes and
+    /// `R  old -> new` rename entries.
+    fn parse_porcelain(output: &str, path: &str) -> GitRepository {
+        let files = crate::git_repository::parse_porcelain_files(output);
+        GitRepository::from_files(path.to_string(), files, None)
+    }
+}
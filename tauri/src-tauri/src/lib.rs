@@ -1,6 +1,7 @@
 pub mod api_client;
 pub mod api_testing;
 pub mod app_mover;
+pub mod app_opener;
 #[cfg(target_os = "macos")]
 pub mod applescript;
 pub mod auth_cache;
@@ -15,7 +16,11 @@ pub mod fs_api;
 pub mod git_app_launcher;
 pub mod git_monitor;
 pub mod git_repository;
+pub mod git_tray;
+pub mod git_watcher;
 pub mod keychain;
+#[cfg(target_os = "linux")]
+pub mod linux_env;
 pub mod log_collector;
 pub mod menubar_popover;
 pub mod network_utils;
@@ -26,13 +31,17 @@ pub mod port_conflict;
 pub mod power_manager;
 pub mod process_tracker;
 pub mod status_indicator;
+pub mod service_manager;
 pub mod session_monitor;
 pub mod settings;
+pub mod shortcuts;
 pub mod state;
 pub mod tailscale;
+pub mod tasks;
 pub mod terminal;
 pub mod terminal_detector;
 pub mod terminal_integrations;
+pub mod terminal_launcher;
 pub mod terminal_spawn_service;
 pub mod tray_menu;
 pub mod tty_forward;
@@ -42,6 +51,7 @@ pub mod updater;
 pub mod url_scheme;
 pub mod welcome;
 pub mod window_enumerator;
+pub mod window_manager;
 pub mod window_matcher;
 pub mod window_tracker;
 
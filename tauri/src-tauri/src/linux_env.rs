@@ -0,0 +1,151 @@
+//! Shared environment sanitization for child processes spawned on Linux.
+//!
+//! When VibeTunnel ships as an AppImage/Flatpak/Snap, the bundle runtime
+//! injects variables (`LD_LIBRARY_PATH`, `GST_PLUGIN_SYSTEM_PATH`,
+//! `GIO_MODULE_DIR`, `PATH`, `XDG_DATA_DIRS`) that point into the bundle.
+//! Every `Command`/`open::that` call that spawns something other than
+//! VibeTunnel itself (Tailscale, a terminal emulator, `wmctrl`) needs to
+//! clean these up first, or the child can crash or pick up the wrong libs.
+
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use tracing::debug;
+
+/// PATH-like environment variables that need dedup/bundle-path filtering
+/// before being handed to a child process spawned from inside a bundle.
+const PATH_LIKE_VARS: &[&str] = &["PATH", "LD_LIBRARY_PATH", "XDG_DATA_DIRS", "XDG_CONFIG_DIRS"];
+
+/// Library/plugin-search variables that point into the bundle and should be
+/// cleared entirely rather than merged, since nothing outside the bundle is
+/// expected to be compatible with their contents.
+const RESET_VARS: &[&str] = &[
+    "GST_PLUGIN_SYSTEM_PATH",
+    "GST_PLUGIN_PATH",
+    "GIO_MODULE_DIR",
+    "GTK_PATH",
+    "GTK_EXE_PREFIX",
+    "GTK_DATA_PREFIX",
+];
+
+/// Returns true when VibeTunnel itself is running from an AppImage.
+pub fn is_appimage() -> bool {
+    std::env::var("APPIMAGE").is_ok() || std::env::var("APPDIR").is_ok()
+}
+
+/// Returns true when VibeTunnel itself is running from a Flatpak sandbox.
+pub fn is_flatpak() -> bool {
+    std::path::Path::new("/.flatpak-info").exists()
+        || std::env::var("container").map(|v| v == "flatpak").unwrap_or(false)
+}
+
+/// Returns true when VibeTunnel itself is running from a Snap package.
+pub fn is_snap() -> bool {
+    std::env::var("SNAP").is_ok()
+}
+
+/// Returns true if VibeTunnel is running inside any kind of Linux desktop sandbox/bundle.
+pub fn is_sandboxed() -> bool {
+    is_appimage() || is_flatpak() || is_snap()
+}
+
+fn sandbox_kind() -> &'static str {
+    if is_appimage() {
+        "AppImage"
+    } else if is_flatpak() {
+        "Flatpak"
+    } else if is_snap() {
+        "Snap"
+    } else {
+        "none"
+    }
+}
+
+/// The directory the current bundle unpacks/mounts itself at, if any —
+/// `PATH`/`LD_LIBRARY_PATH` entries under here get dropped for spawned
+/// children since they only make sense for VibeTunnel's own process.
+fn bundle_root() -> Option<PathBuf> {
+    std::env::var("APPDIR")
+        .or_else(|_| std::env::var("SNAP"))
+        .ok()
+        .map(PathBuf::from)
+        .or_else(|| is_flatpak().then(|| PathBuf::from("/app")))
+}
+
+/// Split a `:`-separated pathlist, drop entries that resolve inside the
+/// bundle directory, and de-duplicate while preserving order — but when an
+/// entry repeats, keep it at the position of its *lowest-priority* (last)
+/// occurrence, so a bundled path earlier in the list doesn't shadow the
+/// system path that appears later.
+pub fn dedupe_pathlist(value: &str) -> String {
+    let bundle_root = bundle_root();
+    let entries: Vec<&str> = value
+        .split(':')
+        .filter(|s| !s.is_empty())
+        .filter(|entry| match &bundle_root {
+            Some(root) => !PathBuf::from(entry).starts_with(root),
+            None => true,
+        })
+        .collect();
+
+    let mut last_index_of = HashMap::new();
+    for (idx, entry) in entries.iter().enumerate() {
+        last_index_of.insert(*entry, idx);
+    }
+
+    let mut seen = HashSet::new();
+    let mut result = Vec::new();
+    for (idx, entry) in entries.iter().enumerate() {
+        if last_index_of[entry] != idx {
+            continue;
+        }
+        if seen.insert(*entry) {
+            result.push(*entry);
+        }
+    }
+
+    result.join(":")
+}
+
+/// Prefer a launcher-stashed original value (`{VAR}_ORIG`) over the
+/// bundle-mutated one, falling back to the current value.
+fn original_or_current(var: &str) -> Option<String> {
+    std::env::var(format!("{}_ORIG", var))
+        .or_else(|_| std::env::var(var))
+        .ok()
+}
+
+/// Build the set of environment overrides to apply to a child process
+/// spawned from inside a sandboxed runtime: PATH-like variables restored to
+/// their pre-bundle value (if stashed) and deduped/filtered, library/plugin
+/// search variables reset outright. An empty string means "unset this
+/// variable" rather than exporting an empty value — see `apply_to_command`.
+pub fn sanitized_env() -> Vec<(String, String)> {
+    let mut env = Vec::new();
+
+    for var in PATH_LIKE_VARS {
+        if let Some(value) = original_or_current(var) {
+            env.push((var.to_string(), dedupe_pathlist(&value)));
+        }
+    }
+
+    if is_sandboxed() {
+        debug!("Detected sandboxed runtime ({}), resetting library search variables", sandbox_kind());
+        for var in RESET_VARS {
+            env.push((var.to_string(), String::new()));
+        }
+    }
+
+    env
+}
+
+/// Apply `sanitized_env()` to a `std::process::Command`, unsetting any
+/// variable that ended up empty rather than exporting an empty string.
+pub fn apply_to_command(command: &mut std::process::Command) {
+    for (key, value) in sanitized_env() {
+        if value.is_empty() {
+            command.env_remove(&key);
+        } else {
+            command.env(&key, &value);
+        }
+    }
+}
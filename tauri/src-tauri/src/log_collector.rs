@@ -1,8 +1,13 @@
 use std::collections::VecDeque;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use serde::{Deserialize, Serialize};
 use tauri::{AppHandle, Emitter};
+use tracing::field::{Field, Visit};
+use tracing::Subscriber;
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::Layer;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LogEntry {
@@ -15,6 +20,19 @@ pub struct LogCollector {
     buffer: Arc<RwLock<VecDeque<LogEntry>>>,
     max_size: usize,
     app_handle: Arc<RwLock<Option<AppHandle>>>,
+    /// Runtime-adjustable verbosity floor for the `LogCollectorLayer`, as an
+    /// ordinal matching `tracing::Level` (ERROR=0 .. TRACE=4).
+    level_filter: AtomicUsize,
+}
+
+fn level_ordinal(level: &tracing::Level) -> usize {
+    match *level {
+        tracing::Level::ERROR => 0,
+        tracing::Level::WARN => 1,
+        tracing::Level::INFO => 2,
+        tracing::Level::DEBUG => 3,
+        tracing::Level::TRACE => 4,
+    }
 }
 
 impl LogCollector {
@@ -23,6 +41,7 @@ impl LogCollector {
             buffer: Arc::new(RwLock::new(VecDeque::with_capacity(max_size))),
             max_size,
             app_handle: Arc::new(RwLock::new(None)),
+            level_filter: AtomicUsize::new(level_ordinal(&tracing::Level::INFO)),
         }
     }
 
@@ -30,6 +49,15 @@ impl LogCollector {
         *self.app_handle.write().await = Some(app_handle);
     }
 
+    /// Raise or lower the verbosity the `LogCollectorLayer` admits at runtime.
+    pub fn set_level_filter(&self, level: tracing::Level) {
+        self.level_filter.store(level_ordinal(&level), Ordering::Relaxed);
+    }
+
+    fn admits(&self, level: &tracing::Level) -> bool {
+        level_ordinal(level) <= self.level_filter.load(Ordering::Relaxed)
+    }
+
     pub async fn add_log(&self, level: &str, message: String) {
         let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S%.3f").to_string();
         let entry = LogEntry {
@@ -51,6 +79,31 @@ impl LogCollector {
         buffer.push_back(entry);
     }
 
+    /// Non-blocking variant used from the tracing `Layer`: skips the push
+    /// entirely (rather than blocking the calling thread/span) if the
+    /// buffer lock is currently held elsewhere.
+    fn try_add_log(&self, level: &str, message: String) {
+        let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S%.3f").to_string();
+        let entry = LogEntry {
+            timestamp,
+            level: level.to_string(),
+            message,
+        };
+
+        if let Ok(guard) = self.app_handle.try_read() {
+            if let Some(ref app) = *guard {
+                let _ = app.emit("server-log", &entry);
+            }
+        }
+
+        if let Ok(mut buffer) = self.buffer.try_write() {
+            if buffer.len() >= self.max_size {
+                buffer.pop_front();
+            }
+            buffer.push_back(entry);
+        }
+    }
+
     pub async fn get_logs(&self) -> Vec<LogEntry> {
         self.buffer.read().await.iter().cloned().collect()
     }
@@ -70,4 +123,89 @@ lazy_static::lazy_static! {
 // Initialize the log collector with app handle
 pub async fn init_log_collector(app_handle: AppHandle) {
     SERVER_LOG_COLLECTOR.set_app_handle(app_handle).await;
+}
+
+/// Captures the `message` field of a tracing event, plus a best-effort
+/// rendering of any remaining key/value fields.
+#[derive(Default)]
+struct MessageVisitor {
+    message: Option<String>,
+    extra: String,
+}
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            self.message = Some(format!("{:?}", value));
+        } else {
+            if !self.extra.is_empty() {
+                self.extra.push(' ');
+            }
+            self.extra.push_str(&format!("{}={:?}", field.name(), value));
+        }
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message = Some(value.to_string());
+        } else {
+            if !self.extra.is_empty() {
+                self.extra.push(' ');
+            }
+            self.extra.push_str(&format!("{}={}", field.name(), value));
+        }
+    }
+}
+
+/// A `tracing_subscriber::Layer` that mirrors every tracing event admitted by
+/// the collector's level filter (INFO and above unless changed with
+/// `set_level_filter`) into `SERVER_LOG_COLLECTOR`, so the in-app log panel
+/// reflects the app's structured logs without each call site manually
+/// invoking `add_log`.
+///
+/// `on_event` is a synchronous callback that may run inside an active
+/// tracing span, so it must never block: it goes through
+/// `LogCollector::try_add_log`, which uses `try_read`/`try_write` and simply
+/// drops the entry rather than contending for the lock.
+pub struct LogCollectorLayer {
+    collector: Arc<LogCollector>,
+}
+
+impl LogCollectorLayer {
+    pub fn new(collector: Arc<LogCollector>) -> Self {
+        Self { collector }
+    }
+}
+
+impl Default for LogCollectorLayer {
+    fn default() -> Self {
+        Self::new(SERVER_LOG_COLLECTOR.clone())
+    }
+}
+
+impl<S> Layer<S> for LogCollectorLayer
+where
+    S: Subscriber,
+{
+    fn on_event(&self, event: &tracing::Event<'_>, _ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        let level = metadata.level();
+
+        if !self.collector.admits(level) {
+            return;
+        }
+
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+
+        let mut message = visitor.message.unwrap_or_default();
+        if !visitor.extra.is_empty() {
+            message.push_str(" (");
+            message.push_str(&visitor.extra);
+            message.push(')');
+        }
+
+        let message = format!("[{}] {}", metadata.target(), message);
+        self.collector.try_add_log(&level.to_string(), message);
+    }
 }
\ No newline at end of file
@@ -1,6 +1,54 @@
+use serde::{Deserialize, Serialize};
 use tauri::{AppHandle, LogicalPosition, Manager, WebviewUrl, WebviewWindowBuilder};
 use tracing::{debug, error};
 
+/// Holds the `NSStatusItem` created by the app's tray setup so
+/// `status_item_screen_frame` has real geometry to query instead of falling
+/// back to the hardcoded heuristic. Tauri's `TrayIcon` doesn't expose the
+/// underlying `NSStatusItem`, so whichever code builds the tray must call
+/// `set_status_item` with it right after creation.
+#[cfg(target_os = "macos")]
+mod tray_status_item {
+    use objc2::rc::Retained;
+    use objc2_app_kit::NSStatusItem;
+    use std::sync::Mutex;
+
+    /// Wraps a `Retained<NSStatusItem>` so it can live in a `static`. AppKit
+    /// objects aren't `Send`, but every access here is already gated behind
+    /// a `MainThreadMarker` at the call site, so the wrapper is sound as
+    /// long as nothing touches the inner item off the main thread.
+    struct StatusItemHandle(Retained<NSStatusItem>);
+    unsafe impl Send for StatusItemHandle {}
+
+    static STATUS_ITEM: Mutex<Option<StatusItemHandle>> = Mutex::new(None);
+
+    pub fn set(item: Retained<NSStatusItem>) {
+        *STATUS_ITEM.lock().unwrap() = Some(StatusItemHandle(item));
+    }
+
+    pub fn get() -> Option<Retained<NSStatusItem>> {
+        STATUS_ITEM.lock().unwrap().as_ref().map(|handle| handle.0.clone())
+    }
+}
+
+/// Stash the `NSStatusItem` created by the tray setup so the popover can
+/// position itself against its real screen geometry. Call this once, right
+/// after the status item is created. Only available on macOS.
+#[cfg(target_os = "macos")]
+pub fn set_status_item(item: objc2::rc::Retained<objc2_app_kit::NSStatusItem>) {
+    tray_status_item::set(item);
+}
+
+/// Titlebar chrome style applied to frameless windows via `tauri-plugin-decorum`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TitlebarStyle {
+    /// Draw an overlay titlebar with macOS traffic lights inset into the content.
+    Overlay,
+    /// No titlebar chrome at all (the original `decorations(false)` behavior).
+    None,
+}
+
 /// Manages the menubar popover window
 pub struct MenubarPopover;
 
@@ -43,11 +91,10 @@ impl MenubarPopover {
         // Configure window for popover behavior
         #[cfg(target_os = "macos")]
         {
-            // For now, skip the macOS-specific configuration
-            // This would require proper cocoa integration which is complex with Tauri v2
-            // The window will still work but won't have the exact native popover behavior
+            Self::apply_titlebar_style(&window, TitlebarStyle::Overlay);
+            Self::apply_vibrancy(&window, true);
         }
-        
+
         // Handle window events
         let app_handle = app.clone();
         window.on_window_event(move |event| {
@@ -90,27 +137,96 @@ impl MenubarPopover {
         }
     }
     
-    /// Calculate the position for the popover based on screen and menu bar
+    /// Calculate the position for the popover based on screen and menu bar.
+    ///
+    /// Prefers the real `NSStatusItem` geometry (via `status_item_screen_frame`)
+    /// and falls back to a fixed position just below the menu bar when no status item button
+    /// can be found (e.g. the tray hasn't been created yet).
     fn calculate_popover_position() -> (f64, f64) {
-        // Default position near the top-right of the screen (where menu bar items typically are)
-        // This is a simplified implementation - in a real app, you'd get the actual
-        // tray icon position
         #[cfg(target_os = "macos")]
         {
-            // Position near the right side of the menu bar
-            // Menu bar is typically 24px tall on macOS
-            let x = 100.0; // This should be calculated based on actual tray icon position
-            let y = 30.0;  // Just below the menu bar
+            if let Some((x, y, width)) = Self::status_item_screen_frame() {
+                let popover_width = 360.0;
+                let centered_x = x + (width / 2.0) - (popover_width / 2.0);
+                return (centered_x, y);
+            }
+
+            // Fallback heuristic: a fixed point just below the menu bar.
+            let x = 100.0;
+            let y = 30.0;
             return (x, y);
         }
-        
+
         #[cfg(not(target_os = "macos"))]
         {
             // For other platforms, position at top-right
             (100.0, 30.0)
         }
     }
+
+    /// Query the real `NSStatusItem` button's frame in screen coordinates,
+    /// flipped against the main screen height (AppKit's origin is
+    /// bottom-left), returning `(x, y, width)` where `y` is the item's
+    /// bottom edge, just below the menu bar.
+    #[cfg(target_os = "macos")]
+    fn status_item_screen_frame() -> Option<(f64, f64, f64)> {
+        use objc2_app_kit::NSScreen;
+        use objc2_foundation::MainThreadMarker;
+
+        let mtm = MainThreadMarker::new()?;
+
+        // `NSStatusBar` doesn't expose "our" item directly, so we read back
+        // whatever the tray setup stashed via `set_status_item`. Falls back
+        // to the heuristic in `calculate_popover_position` if unavailable
+        // (e.g. the tray hasn't been created yet).
+        let item = tray_status_item::get()?;
+
+        let button = item.button(mtm)?;
+        let window = button.window()?;
+        let bounds = button.bounds();
+        let frame_in_screen = window.convertRectToScreen(bounds);
+
+        let main_screen_height = NSScreen::mainScreen(mtm).map(|s| s.frame().size.height).unwrap_or(0.0);
+        let flipped_y = main_screen_height - frame_in_screen.origin.y;
+
+        Some((frame_in_screen.origin.x, flipped_y, frame_in_screen.size.width))
+    }
     
+    /// Apply (or remove) the overlay titlebar chrome, drawing macOS traffic
+    /// lights inset into the content instead of a native title bar.
+    #[cfg(target_os = "macos")]
+    fn apply_titlebar_style(window: &tauri::WebviewWindow, style: TitlebarStyle) {
+        use tauri_plugin_decorum::WebviewWindowExt;
+
+        match style {
+            TitlebarStyle::Overlay => {
+                window.create_overlay_titlebar().ok();
+                window.set_traffic_lights_inset(12.0, 16.0).ok();
+            }
+            TitlebarStyle::None => {
+                // No native chrome to restore; the window stays frameless.
+            }
+        }
+    }
+
+    /// Toggle `NSVisualEffectView`-backed background blur so the popover
+    /// reads as a native translucent panel.
+    #[cfg(target_os = "macos")]
+    fn apply_vibrancy(window: &tauri::WebviewWindow, enabled: bool) {
+        if enabled {
+            if let Err(e) = window_vibrancy::apply_vibrancy(
+                window,
+                window_vibrancy::NSVisualEffectMaterial::Popover,
+                None,
+                None,
+            ) {
+                error!("Failed to apply popover vibrancy: {}", e);
+            }
+        } else if let Err(e) = window_vibrancy::clear_vibrancy(window) {
+            error!("Failed to clear popover vibrancy: {}", e);
+        }
+    }
+
     /// Update the popover position based on the tray icon location
     pub fn update_position(app: &AppHandle, x: f64, y: f64) -> Result<(), String> {
         if let Some(window) = app.get_webview_window("menubar-popover") {
@@ -140,4 +256,36 @@ pub fn hide_menubar_popover(app: AppHandle) -> Result<(), String> {
 #[tauri::command]
 pub fn toggle_menubar_popover(app: AppHandle) -> Result<(), String> {
     MenubarPopover::toggle(&app)
+}
+
+/// Let the frontend switch the popover between the overlay titlebar and a
+/// fully chromeless window. No-op on non-macOS platforms.
+#[tauri::command]
+pub fn set_titlebar_style(app: AppHandle, style: TitlebarStyle) -> Result<(), String> {
+    let window = app
+        .get_webview_window("menubar-popover")
+        .ok_or_else(|| "Menubar popover is not open".to_string())?;
+
+    #[cfg(target_os = "macos")]
+    MenubarPopover::apply_titlebar_style(&window, style);
+    #[cfg(not(target_os = "macos"))]
+    let _ = (window, style);
+
+    Ok(())
+}
+
+/// Enable or disable the `NSVisualEffectView` background blur on the popover.
+/// No-op on non-macOS platforms.
+#[tauri::command]
+pub fn set_vibrancy(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let window = app
+        .get_webview_window("menubar-popover")
+        .ok_or_else(|| "Menubar popover is not open".to_string())?;
+
+    #[cfg(target_os = "macos")]
+    MenubarPopover::apply_vibrancy(&window, enabled);
+    #[cfg(not(target_os = "macos"))]
+    let _ = (window, enabled);
+
+    Ok(())
 }
\ No newline at end of file
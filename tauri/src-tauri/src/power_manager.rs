@@ -14,6 +14,10 @@ pub struct PowerManager {
     assertion_id: std::sync::Mutex<Option<u32>>,
     #[cfg(target_os = "windows")]
     _previous_state: std::sync::Mutex<Option<u32>>,
+    /// Holds the `org.freedesktop.login1` inhibitor lock fd while held; closing
+    /// it (via `Drop`) releases the inhibition.
+    #[cfg(target_os = "linux")]
+    inhibitor: std::sync::Mutex<Option<std::os::fd::OwnedFd>>,
 }
 
 impl PowerManager {
@@ -24,6 +28,8 @@ impl PowerManager {
             assertion_id: std::sync::Mutex::new(None),
             #[cfg(target_os = "windows")]
             _previous_state: std::sync::Mutex::new(None),
+            #[cfg(target_os = "linux")]
+            inhibitor: std::sync::Mutex::new(None),
         }
     }
 
@@ -196,15 +202,55 @@ impl PowerManager {
 
     #[cfg(target_os = "linux")]
     fn prevent_sleep_linux(&self) -> Result<(), String> {
-        // On Linux, we can use systemd-inhibit or DBus to prevent sleep
-        // For now, we'll use a simple implementation
-        debug!("Linux sleep prevention not implemented");
+        use std::os::fd::OwnedFd;
+        use zbus::blocking::Connection;
+        use zbus::zvariant::OwnedFd as ZOwnedFd;
+
+        let connection = match Connection::system() {
+            Ok(connection) => connection,
+            Err(e) => {
+                debug!("No system D-Bus available, skipping sleep inhibition: {}", e);
+                return Ok(());
+            }
+        };
+
+        let reply = connection.call_method(
+            Some("org.freedesktop.login1"),
+            "/org/freedesktop/login1",
+            Some("org.freedesktop.login1.Manager"),
+            "Inhibit",
+            &(
+                "idle:sleep",
+                "VibeTunnel",
+                "VibeTunnel is running terminal sessions",
+                "block",
+            ),
+        );
+
+        let reply = match reply {
+            Ok(reply) => reply,
+            Err(e) => {
+                debug!("logind Inhibit call failed, skipping sleep inhibition: {}", e);
+                return Ok(());
+            }
+        };
+
+        let fd: ZOwnedFd = reply
+            .body()
+            .deserialize()
+            .map_err(|e| format!("Failed to read logind inhibitor fd: {}", e))?;
+        let fd: OwnedFd = fd.into();
+
+        let mut guard = self.inhibitor.lock().unwrap();
+        *guard = Some(fd);
         Ok(())
     }
 
     #[cfg(target_os = "linux")]
     fn allow_sleep_linux(&self) -> Result<(), String> {
-        debug!("Linux sleep allowance not implemented");
+        // Dropping the fd closes it, which releases the logind inhibitor lock.
+        let mut guard = self.inhibitor.lock().unwrap();
+        guard.take();
         Ok(())
     }
 }
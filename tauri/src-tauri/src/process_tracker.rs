@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use tracing::{debug, info, warn};
 
 /// Process information
@@ -6,12 +7,58 @@ pub struct ProcessInfo {
     pub pid: u32,
     pub ppid: u32,
     pub name: String,
+    /// Opaque, platform-specific process start marker (Unix-epoch seconds via
+    /// libproc's `pbi_start_tvsec` on macOS, clock ticks since boot from field
+    /// 22 of `/proc/<pid>/stat` on Linux, raw FILETIME ticks on Windows). Only meaningful for detecting
+    /// whether `pid` still refers to the same process — never compare
+    /// start times taken from different platforms.
+    pub start_time: u64,
+}
+
+/// Flags and the trailing positional argument parsed from a process's argv,
+/// split the way most CLI tools distinguish `--long` from `-s` options.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProcessArgs {
+    pub long_opts: Vec<String>,
+    pub short_opts: Vec<String>,
+    pub positional: Option<String>,
+}
+
+/// What a tracked terminal is actually running, as classified from its argv —
+/// lets the UI label a session by the git/grep command underneath it, the
+/// same way diff-pager tooling inspects its calling process to adapt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CallingProcess {
+    GitDiff(ProcessArgs),
+    GitLog(ProcessArgs),
+    GitShow(ProcessArgs),
+    GitGrep(ProcessArgs),
+    OtherGrep { binary: String, args: ProcessArgs },
+    Other,
 }
 
 /// Handles process tree traversal and process information extraction
 pub struct ProcessTracker;
 
 impl ProcessTracker {
+    /// Enumerate every process on the system exactly once. Walking a process
+    /// tree against this in-memory table avoids the O(depth) syscalls/spawns
+    /// that re-querying the OS once per ancestor would cost.
+    pub fn snapshot() -> HashMap<u32, ProcessInfo> {
+        #[cfg(target_os = "macos")]
+        {
+            Self::snapshot_macos()
+        }
+        #[cfg(target_os = "windows")]
+        {
+            Self::snapshot_windows()
+        }
+        #[cfg(target_os = "linux")]
+        {
+            Self::snapshot_linux()
+        }
+    }
+
     /// Get the parent process ID of a given process
     pub fn get_parent_process_id(pid: u32) -> Option<u32> {
         #[cfg(target_os = "macos")]
@@ -44,22 +91,28 @@ impl ProcessTracker {
         }
     }
 
-    /// Log the process tree for debugging
-    pub fn log_process_tree(pid: u32) {
+    /// Log the process tree for debugging. Pass a `snapshot()` table to walk
+    /// it in memory instead of re-querying the OS for every ancestor.
+    pub fn log_process_tree(pid: u32, snapshot: Option<&HashMap<u32, ProcessInfo>>) {
         debug!("Process tree for PID {}:", pid);
-        
+
         let mut current_pid = pid;
         let mut depth = 0;
-        
+
         while depth < 20 {
-            if let Some(info) = Self::get_process_info(current_pid) {
+            let info = match snapshot {
+                Some(table) => table.get(&current_pid).cloned(),
+                None => Self::get_process_info(current_pid),
+            };
+
+            if let Some(info) = info {
                 let indent = "  ".repeat(depth);
                 debug!("{}PID {}: {} (parent: {})", indent, current_pid, info.name, info.ppid);
-                
+
                 if info.ppid == 0 || info.ppid == 1 {
                     break;
                 }
-                
+
                 current_pid = info.ppid;
                 depth += 1;
             } else {
@@ -68,50 +121,261 @@ impl ProcessTracker {
         }
     }
 
-    /// Find the terminal process in the ancestry of a given PID
-    pub fn find_terminal_ancestor(pid: u32, max_depth: usize) -> Option<u32> {
+    /// Whether `pid` still refers to the process that had `expected_start_time`.
+    /// A PID the OS has recycled for an unrelated process will have a
+    /// different start time, so callers holding onto a cached ancestor PID
+    /// should use this to decide whether to discard it rather than trust a
+    /// bare PID match.
+    pub fn is_same_process(pid: u32, expected_start_time: u64) -> bool {
+        Self::get_process_info(pid).map(|info| info.start_time == expected_start_time).unwrap_or(false)
+    }
+
+    /// Classify what a process is actually running by reading its full argv
+    /// (macOS: `sysctl KERN_PROCARGS2`; Linux: `/proc/<pid>/cmdline`;
+    /// Windows: `Win32_Process.CommandLine`).
+    pub fn classify_process(pid: u32) -> CallingProcess {
+        match Self::process_argv(pid) {
+            Some(argv) => Self::classify_argv(&argv),
+            None => CallingProcess::Other,
+        }
+    }
+
+    fn classify_argv(argv: &[String]) -> CallingProcess {
+        let Some(binary) = argv.first() else {
+            return CallingProcess::Other;
+        };
+        let binary_name = std::path::Path::new(binary).file_name().and_then(|n| n.to_str()).unwrap_or(binary.as_str());
+
+        match binary_name {
+            "git" => {
+                let Some((subcommand, rest)) = Self::find_git_subcommand(&argv[1..]) else {
+                    return CallingProcess::Other;
+                };
+                let args = Self::parse_process_args(rest);
+                match subcommand {
+                    "diff" => CallingProcess::GitDiff(args),
+                    "log" => CallingProcess::GitLog(args),
+                    "show" => CallingProcess::GitShow(args),
+                    "grep" => CallingProcess::GitGrep(args),
+                    _ => CallingProcess::Other,
+                }
+            }
+            "rg" | "ag" | "ack" | "grep" => CallingProcess::OtherGrep {
+                binary: binary_name.to_string(),
+                args: Self::parse_process_args(&argv[1..]),
+            },
+            _ => CallingProcess::Other,
+        }
+    }
+
+    /// Walk past `git`'s leading global options (e.g. `-C <path>`,
+    /// `--no-pager`, `-c key=val`) to find the actual subcommand, returning
+    /// it along with the remaining args to classify. `-C` and `-c` are taken
+    /// to consume the following value; any other leading flag is skipped as
+    /// a single token.
+    fn find_git_subcommand(args: &[String]) -> Option<(&str, &[String])> {
+        let mut i = 0;
+        while i < args.len() {
+            let arg = args[i].as_str();
+            if !arg.starts_with('-') {
+                return Some((arg, &args[i + 1..]));
+            }
+            match arg {
+                "-C" | "-c" => i += 2,
+                _ => i += 1,
+            }
+        }
+        None
+    }
+
+    /// Split argv into long (`--relative`) and short (`-p`) options, keeping
+    /// the last non-flag argument as the positional (typically a pathspec or
+    /// commit range).
+    fn parse_process_args(args: &[String]) -> ProcessArgs {
+        let mut long_opts = Vec::new();
+        let mut short_opts = Vec::new();
+        let mut positional = None;
+
+        for arg in args {
+            if let Some(long_opt) = arg.strip_prefix("--") {
+                long_opts.push(format!("--{}", long_opt.split('=').next().unwrap_or(long_opt)));
+            } else if arg.starts_with('-') && arg.len() > 1 {
+                short_opts.push(arg.clone());
+            } else {
+                positional = Some(arg.clone());
+            }
+        }
+
+        ProcessArgs { long_opts, short_opts, positional }
+    }
+
+    /// Fetch a process's full argv.
+    fn process_argv(pid: u32) -> Option<Vec<String>> {
+        #[cfg(target_os = "macos")]
+        {
+            Self::process_argv_macos(pid)
+        }
+        #[cfg(target_os = "windows")]
+        {
+            Self::process_argv_windows(pid)
+        }
+        #[cfg(target_os = "linux")]
+        {
+            Self::process_argv_linux(pid)
+        }
+    }
+
+    /// Find the terminal process in the ancestry of a given PID, returning
+    /// its PID and start time so callers can re-validate the match later via
+    /// `is_same_process` instead of trusting a bare PID that may have been
+    /// recycled. Pass a `snapshot()` table to resolve the whole walk against
+    /// a single in-memory process table instead of re-querying the OS per
+    /// ancestor.
+    pub fn find_terminal_ancestor(pid: u32, max_depth: usize, snapshot: Option<&HashMap<u32, ProcessInfo>>) -> Option<(u32, u64)> {
         let mut current_pid = pid;
         let mut depth = 0;
-        
+
         while depth < max_depth {
-            if let Some(parent_pid) = Self::get_parent_process_id(current_pid) {
+            let parent_pid = match snapshot {
+                Some(table) => table.get(&current_pid).map(|info| info.ppid),
+                None => Self::get_parent_process_id(current_pid),
+            };
+
+            if let Some(parent_pid) = parent_pid {
                 debug!("Checking ancestor process PID: {} at depth {}", parent_pid, depth + 1);
-                
+
+                let info = match snapshot {
+                    Some(table) => table.get(&parent_pid).cloned(),
+                    None => Self::get_process_info(parent_pid),
+                };
+
                 // Check if this is a terminal process
-                if let Some(info) = Self::get_process_info(parent_pid) {
+                if let Some(info) = info {
                     let terminal_processes = vec![
                         "Terminal", "iTerm2", "alacritty", "kitty", "wezterm",
                         "gnome-terminal", "konsole", "xterm", "cmd.exe", "powershell.exe",
                         "WindowsTerminal.exe"
                     ];
-                    
+
                     if terminal_processes.iter().any(|&tp| info.name.contains(tp)) {
                         info!("Found terminal ancestor: {} (PID: {})", info.name, parent_pid);
-                        return Some(parent_pid);
+                        return Some((parent_pid, info.start_time));
                     }
                 }
-                
+
                 current_pid = parent_pid;
                 depth += 1;
             } else {
                 break;
             }
         }
-        
+
         None
     }
 
     #[cfg(target_os = "macos")]
     fn get_parent_pid_macos(pid: u32) -> Option<u32> {
+        if let Some(info) = Self::proc_bsd_info(pid) {
+            return Some(info.pbi_ppid);
+        }
+
+        Self::get_parent_pid_macos_ps(pid)
+    }
+
+    #[cfg(target_os = "macos")]
+    fn get_process_info_macos(pid: u32) -> Option<ProcessInfo> {
+        if let Some(info) = Self::proc_bsd_info(pid) {
+            let name = Self::comm_to_string(&info.pbi_name)
+                .filter(|n| !n.is_empty())
+                .or_else(|| Self::comm_to_string(&info.pbi_comm))
+                .unwrap_or_else(|| format!("Process {}", pid));
+            return Some(ProcessInfo {
+                pid,
+                ppid: info.pbi_ppid,
+                name,
+                start_time: info.pbi_start_tvsec,
+            });
+        }
+
+        Self::get_process_info_macos_ps(pid)
+    }
+
+    /// Fetch a process's BSD info via `proc_pidinfo(PROC_PIDTBSDINFO)` — this
+    /// is a single syscall, unlike forking `ps` per ancestor in a process-tree
+    /// walk. Returns `None` on syscall failure so callers can fall back to
+    /// the `ps`-based path (e.g. for processes owned by another user).
+    #[cfg(target_os = "macos")]
+    fn proc_bsd_info(pid: u32) -> Option<libc::proc_bsdinfo> {
+        let mut info: libc::proc_bsdinfo = unsafe { std::mem::zeroed() };
+        let size = std::mem::size_of::<libc::proc_bsdinfo>() as i32;
+
+        let written = unsafe { libc::proc_pidinfo(pid as i32, libc::PROC_PIDTBSDINFO, 0, &mut info as *mut _ as *mut libc::c_void, size) };
+
+        if written == size {
+            Some(info)
+        } else {
+            None
+        }
+    }
+
+    #[cfg(target_os = "macos")]
+    fn comm_to_string(bytes: &[libc::c_char]) -> Option<String> {
+        let end = bytes.iter().position(|&b| b == 0)?;
+        let as_u8: Vec<u8> = bytes[..end].iter().map(|&b| b as u8).collect();
+        String::from_utf8(as_u8).ok()
+    }
+
+    /// Best-effort start time for the `ps`-based fallback path, which has no
+    /// direct equivalent of libproc's `pbi_start_tvsec`: derive it from the
+    /// process's elapsed running time instead.
+    #[cfg(target_os = "macos")]
+    fn start_time_from_etime_macos(pid: u32) -> u64 {
+        use std::process::Command;
+        use std::time::{SystemTime, UNIX_EPOCH};
+
+        let Ok(output) = Command::new("ps").args(&["-p", &pid.to_string(), "-o", "etime="]).output() else {
+            return 0;
+        };
+        if !output.status.success() {
+            return 0;
+        }
+
+        let Some(elapsed_secs) = Self::parse_etime(String::from_utf8_lossy(&output.stdout).trim()) else {
+            return 0;
+        };
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+        now.saturating_sub(elapsed_secs)
+    }
+
+    /// Parse `ps`'s `etime` format: `[[DD-]HH:]MM:SS`.
+    #[cfg(target_os = "macos")]
+    fn parse_etime(etime: &str) -> Option<u64> {
+        let (days, rest) = match etime.split_once('-') {
+            Some((d, rest)) => (d.parse::<u64>().ok()?, rest),
+            None => (0, etime),
+        };
+
+        let parts: Vec<&str> = rest.split(':').collect();
+        let (hours, minutes, seconds) = match parts.as_slice() {
+            [h, m, s] => (h.parse::<u64>().ok()?, m.parse::<u64>().ok()?, s.parse::<u64>().ok()?),
+            [m, s] => (0, m.parse::<u64>().ok()?, s.parse::<u64>().ok()?),
+            _ => return None,
+        };
+
+        Some(days * 86400 + hours * 3600 + minutes * 60 + seconds)
+    }
+
+    #[cfg(target_os = "macos")]
+    fn get_parent_pid_macos_ps(pid: u32) -> Option<u32> {
         use std::process::Command;
         use std::str;
-        
-        // Use ps command which is more reliable and doesn't require unsafe kernel structs
+
         let output = Command::new("ps")
             .args(&["-o", "ppid=", "-p", &pid.to_string()])
             .output()
             .ok()?;
-            
+
         if output.status.success() {
             let ppid_str = str::from_utf8(&output.stdout).ok()?.trim();
             ppid_str.parse::<u32>().ok()
@@ -121,9 +385,9 @@ impl ProcessTracker {
     }
 
     #[cfg(target_os = "macos")]
-    fn get_process_info_macos(pid: u32) -> Option<ProcessInfo> {
+    fn get_process_info_macos_ps(pid: u32) -> Option<ProcessInfo> {
         use std::process::Command;
-        
+
         // Use ps command as a fallback for process info
         match Command::new("ps")
             .args(&["-p", &pid.to_string(), "-o", "ppid=,comm="])
@@ -136,7 +400,8 @@ impl ProcessTracker {
                     if parts.len() >= 2 {
                         let ppid = parts[0].parse::<u32>().unwrap_or(0);
                         let name = parts[1..].join(" ");
-                        return Some(ProcessInfo { pid, ppid, name });
+                        let start_time = Self::start_time_from_etime_macos(pid);
+                        return Some(ProcessInfo { pid, ppid, name, start_time });
                     }
                 }
             }
@@ -144,13 +409,14 @@ impl ProcessTracker {
                 warn!("Failed to run ps command: {}", e);
             }
         }
-        
+
         // Try to at least get parent PID
-        if let Some(ppid) = Self::get_parent_pid_macos(pid) {
+        if let Some(ppid) = Self::get_parent_pid_macos_ps(pid) {
             Some(ProcessInfo {
                 pid,
                 ppid,
                 name: format!("Process {}", pid),
+                start_time: Self::start_time_from_etime_macos(pid),
             })
         } else {
             None
@@ -221,6 +487,7 @@ impl ProcessTracker {
                             pid,
                             ppid: process_entry.th32ParentProcessID,
                             name,
+                            start_time: Self::process_start_time_windows(pid),
                         });
                     }
                     
@@ -232,10 +499,39 @@ impl ProcessTracker {
             
             let _ = windows::Win32::Foundation::CloseHandle(snapshot);
         }
-        
+
         None
     }
 
+    /// Process creation time as raw `FILETIME` ticks (100ns units since
+    /// 1601), fetched via `GetProcessTimes`. Only used to detect PID reuse,
+    /// so the absolute value never needs interpreting as a real timestamp.
+    #[cfg(target_os = "windows")]
+    fn process_start_time_windows(pid: u32) -> u64 {
+        use windows::Win32::Foundation::{CloseHandle, FILETIME};
+        use windows::Win32::System::Threading::{GetProcessTimes, OpenProcess, PROCESS_QUERY_LIMITED_INFORMATION};
+
+        unsafe {
+            let Ok(handle) = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid) else {
+                return 0;
+            };
+
+            let mut creation = FILETIME::default();
+            let mut exit = FILETIME::default();
+            let mut kernel = FILETIME::default();
+            let mut user = FILETIME::default();
+
+            let got_times = GetProcessTimes(handle, &mut creation, &mut exit, &mut kernel, &mut user).is_ok();
+            let _ = CloseHandle(handle);
+
+            if !got_times {
+                return 0;
+            }
+
+            ((creation.dwHighDateTime as u64) << 32) | creation.dwLowDateTime as u64
+        }
+    }
+
     #[cfg(target_os = "linux")]
     fn get_parent_pid_linux(pid: u32) -> Option<u32> {
         use std::fs;
@@ -267,18 +563,259 @@ impl ProcessTracker {
     #[cfg(target_os = "linux")]
     fn get_process_info_linux(pid: u32) -> Option<ProcessInfo> {
         use std::fs;
-        
-        // Read /proc/[pid]/stat for ppid
-        let ppid = Self::get_parent_pid_linux(pid)?;
-        
+
+        // Read /proc/[pid]/stat for ppid and starttime (field 22) in one pass
+        let stat_path = format!("/proc/{}/stat", pid);
+        let contents = fs::read_to_string(&stat_path).ok()?;
+        let close_paren = contents.rfind(')')?;
+        let fields: Vec<&str> = contents[close_paren + 1..].split_whitespace().collect();
+
+        let ppid = fields.get(1)?.parse::<u32>().ok()?;
+        // starttime is field 22 overall; `fields` starts at field 3 (state).
+        let start_time = fields.get(19).and_then(|s| s.parse::<u64>().ok()).unwrap_or(0);
+
         // Read /proc/[pid]/comm for process name
         let comm_path = format!("/proc/{}/comm", pid);
         let name = match fs::read_to_string(&comm_path) {
             Ok(contents) => contents.trim().to_string(),
             Err(_) => format!("Process {}", pid),
         };
-        
-        Some(ProcessInfo { pid, ppid, name })
+
+        Some(ProcessInfo { pid, ppid, name, start_time })
+    }
+
+    #[cfg(target_os = "macos")]
+    fn snapshot_macos() -> HashMap<u32, ProcessInfo> {
+        let mut table = HashMap::new();
+
+        let needed = unsafe { libc::proc_listpids(libc::PROC_ALL_PIDS, 0, std::ptr::null_mut(), 0) };
+        if needed <= 0 {
+            return table;
+        }
+
+        // Pad the buffer: the process table can grow between the sizing call
+        // and the listing call.
+        let capacity = (needed as usize) / std::mem::size_of::<libc::pid_t>() + 32;
+        let mut pids: Vec<libc::pid_t> = vec![0; capacity];
+        let size = (pids.len() * std::mem::size_of::<libc::pid_t>()) as i32;
+        let written = unsafe { libc::proc_listpids(libc::PROC_ALL_PIDS, 0, pids.as_mut_ptr() as *mut libc::c_void, size) };
+        if written <= 0 {
+            return table;
+        }
+
+        let count = (written as usize / std::mem::size_of::<libc::pid_t>()).min(pids.len());
+        for &pid in &pids[..count] {
+            if pid <= 0 {
+                continue;
+            }
+            if let Some(info) = Self::get_process_info_macos(pid as u32) {
+                table.insert(pid as u32, info);
+            }
+        }
+
+        table
+    }
+
+    #[cfg(target_os = "windows")]
+    fn snapshot_windows() -> HashMap<u32, ProcessInfo> {
+        use windows::Win32::System::Diagnostics::ToolHelp::{
+            CreateToolhelp32Snapshot, Process32First, Process32Next, PROCESSENTRY32, TH32CS_SNAPPROCESS,
+        };
+
+        let mut table = HashMap::new();
+
+        unsafe {
+            let Ok(snapshot) = CreateToolhelp32Snapshot(TH32CS_SNAPPROCESS, 0) else {
+                return table;
+            };
+
+            let mut process_entry = PROCESSENTRY32 {
+                dwSize: std::mem::size_of::<PROCESSENTRY32>() as u32,
+                ..Default::default()
+            };
+
+            if Process32First(snapshot, &mut process_entry).is_ok() {
+                loop {
+                    let name = String::from_utf16_lossy(&process_entry.szExeFile.iter().take_while(|&&c| c != 0).copied().collect::<Vec<u16>>());
+
+                    let start_time = Self::process_start_time_windows(process_entry.th32ProcessID);
+
+                    table.insert(
+                        process_entry.th32ProcessID,
+                        ProcessInfo {
+                            pid: process_entry.th32ProcessID,
+                            ppid: process_entry.th32ParentProcessID,
+                            name,
+                            start_time,
+                        },
+                    );
+
+                    if !Process32Next(snapshot, &mut process_entry).is_ok() {
+                        break;
+                    }
+                }
+            }
+
+            let _ = windows::Win32::Foundation::CloseHandle(snapshot);
+        }
+
+        table
+    }
+
+    #[cfg(target_os = "linux")]
+    fn snapshot_linux() -> HashMap<u32, ProcessInfo> {
+        use std::fs;
+
+        let mut table = HashMap::new();
+
+        let Ok(entries) = fs::read_dir("/proc") else {
+            return table;
+        };
+
+        for entry in entries.flatten() {
+            let Some(pid) = entry.file_name().to_str().and_then(|n| n.parse::<u32>().ok()) else {
+                continue;
+            };
+            if let Some(info) = Self::get_process_info_linux(pid) {
+                table.insert(pid, info);
+            }
+        }
+
+        table
+    }
+
+    /// macOS has no `/proc`, so argv is fetched via the `KERN_PROCARGS2`
+    /// sysctl, which returns argc followed by the exec path and then each
+    /// argv entry, all NUL-terminated.
+    #[cfg(target_os = "macos")]
+    fn process_argv_macos(pid: u32) -> Option<Vec<String>> {
+        // Not exposed by the `libc` crate's apple bindings.
+        const KERN_PROCARGS2: libc::c_int = 49;
+        let mib = [libc::CTL_KERN, KERN_PROCARGS2, pid as libc::c_int];
+
+        let mut size: libc::size_t = 0;
+        unsafe {
+            if libc::sysctl(mib.as_ptr() as *mut libc::c_int, mib.len() as u32, std::ptr::null_mut(), &mut size, std::ptr::null_mut(), 0) != 0 {
+                return None;
+            }
+        }
+        if size == 0 {
+            return None;
+        }
+
+        let mut buf = vec![0u8; size];
+        unsafe {
+            if libc::sysctl(
+                mib.as_ptr() as *mut libc::c_int,
+                mib.len() as u32,
+                buf.as_mut_ptr() as *mut libc::c_void,
+                &mut size,
+                std::ptr::null_mut(),
+                0,
+            ) != 0
+            {
+                return None;
+            }
+        }
+        buf.truncate(size);
+
+        Self::parse_procargs2(&buf)
+    }
+
+    /// `KERN_PROCARGS2` layout: `argc: i32`, then the process's exec path
+    /// (NUL-terminated, possibly followed by NUL padding), then `argc`
+    /// NUL-terminated argv entries, then the environment.
+    #[cfg(target_os = "macos")]
+    fn parse_procargs2(buf: &[u8]) -> Option<Vec<String>> {
+        if buf.len() < 4 {
+            return None;
+        }
+        let argc = i32::from_ne_bytes(buf[0..4].try_into().ok()?) as usize;
+        let mut offset = 4;
+
+        while offset < buf.len() && buf[offset] != 0 {
+            offset += 1;
+        }
+        while offset < buf.len() && buf[offset] == 0 {
+            offset += 1;
+        }
+
+        let mut argv = Vec::with_capacity(argc);
+        for _ in 0..argc {
+            if offset >= buf.len() {
+                break;
+            }
+            let start = offset;
+            while offset < buf.len() && buf[offset] != 0 {
+                offset += 1;
+            }
+            argv.push(String::from_utf8_lossy(&buf[start..offset]).into_owned());
+            while offset < buf.len() && buf[offset] == 0 {
+                offset += 1;
+            }
+        }
+
+        Some(argv)
+    }
+
+    #[cfg(target_os = "linux")]
+    fn process_argv_linux(pid: u32) -> Option<Vec<String>> {
+        let contents = std::fs::read(format!("/proc/{}/cmdline", pid)).ok()?;
+        if contents.is_empty() {
+            return None;
+        }
+
+        Some(contents.split(|&b| b == 0).filter(|s| !s.is_empty()).map(|s| String::from_utf8_lossy(s).into_owned()).collect())
+    }
+
+    /// Windows exposes argv only through the target process's PEB or WMI;
+    /// shelling out to `Win32_Process.CommandLine` avoids the unsafe
+    /// out-of-process memory read a PEB walk would require.
+    #[cfg(target_os = "windows")]
+    fn process_argv_windows(pid: u32) -> Option<Vec<String>> {
+        use std::process::Command;
+
+        let output = Command::new("powershell")
+            .args(["-NoProfile", "-Command", &format!("(Get-CimInstance Win32_Process -Filter \"ProcessId={}\").CommandLine", pid)])
+            .output()
+            .ok()?;
+
+        if !output.status.success() {
+            return None;
+        }
+
+        let command_line = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        if command_line.is_empty() {
+            return None;
+        }
+
+        Some(Self::split_command_line_windows(&command_line))
+    }
+
+    /// Minimal Windows command-line tokenizer: splits on whitespace, honoring
+    /// double-quoted segments (the common case for `"C:\...\app.exe" --flag`).
+    #[cfg(target_os = "windows")]
+    fn split_command_line_windows(command_line: &str) -> Vec<String> {
+        let mut tokens = Vec::new();
+        let mut current = String::new();
+        let mut in_quotes = false;
+
+        for c in command_line.chars() {
+            match c {
+                '"' => in_quotes = !in_quotes,
+                c if c.is_whitespace() && !in_quotes => {
+                    if !current.is_empty() {
+                        tokens.push(std::mem::take(&mut current));
+                    }
+                }
+                c => current.push(c),
+            }
+        }
+        if !current.is_empty() {
+            tokens.push(current);
+        }
+
+        tokens
     }
 }
 
@@ -0,0 +1,378 @@
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::process::Stdio;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncSeekExt, BufReader};
+use tokio::sync::{mpsc, RwLock};
+use tracing::error;
+
+/// Identifies the managed unit across platforms: a launchd label on macOS, a
+/// systemd user unit name on Linux, and a Windows service name.
+const SERVICE_LABEL: &str = "sh.vibetunnel.server";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ServiceState {
+    NotInstalled,
+    Stopped,
+    Running,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServiceStatus {
+    pub state: ServiceState,
+    pub log_path: Option<String>,
+}
+
+/// Runs VibeTunnel's tunnel/server process as a persistent background OS
+/// service, adjacent to `TailscaleService`'s read/write split: launchd on
+/// macOS, a systemd user unit on Linux, and the Windows service manager.
+pub struct ServiceManager {
+    status: Arc<RwLock<ServiceStatus>>,
+}
+
+impl ServiceManager {
+    pub fn new() -> Self {
+        Self {
+            status: Arc::new(RwLock::new(ServiceStatus {
+                state: ServiceState::NotInstalled,
+                log_path: None,
+            })),
+        }
+    }
+
+    pub async fn status(&self) -> ServiceStatus {
+        self.status.read().await.clone()
+    }
+
+    /// Where service stdout/stderr is redirected on platforms without a
+    /// native log viewer (macOS, Windows).
+    fn log_path() -> PathBuf {
+        std::env::temp_dir().join("vibetunnel-service.log")
+    }
+
+    /// Install and enable the service so it starts at login/boot.
+    pub async fn install(&self, binary_path: String) -> Result<(), String> {
+        let log_path = Self::log_path();
+        tokio::task::spawn_blocking(move || Self::install_blocking(&binary_path, &log_path))
+            .await
+            .map_err(|e| format!("install task panicked: {}", e))??;
+
+        let mut status = self.status.write().await;
+        status.state = ServiceState::Stopped;
+        status.log_path = Some(Self::log_path().display().to_string());
+        Ok(())
+    }
+
+    /// Stop and remove the service.
+    pub async fn uninstall(&self) -> Result<(), String> {
+        tokio::task::spawn_blocking(Self::uninstall_blocking)
+            .await
+            .map_err(|e| format!("uninstall task panicked: {}", e))??;
+
+        let mut status = self.status.write().await;
+        status.state = ServiceState::NotInstalled;
+        status.log_path = None;
+        Ok(())
+    }
+
+    pub async fn start(&self) -> Result<(), String> {
+        tokio::task::spawn_blocking(Self::start_blocking)
+            .await
+            .map_err(|e| format!("start task panicked: {}", e))??;
+        self.status.write().await.state = ServiceState::Running;
+        Ok(())
+    }
+
+    pub async fn stop(&self) -> Result<(), String> {
+        tokio::task::spawn_blocking(Self::stop_blocking)
+            .await
+            .map_err(|e| format!("stop task panicked: {}", e))??;
+        self.status.write().await.state = ServiceState::Stopped;
+        Ok(())
+    }
+
+    fn run(binary: &str, args: &[&str]) -> Result<(), String> {
+        let mut command = std::process::Command::new(binary);
+        command.args(args);
+        #[cfg(target_os = "linux")]
+        crate::linux_env::apply_to_command(&mut command);
+
+        let output = command
+            .output()
+            .map_err(|e| format!("Failed to run {} {}: {}", binary, args.join(" "), e))?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+        Ok(())
+    }
+
+    #[cfg(target_os = "macos")]
+    fn launch_agent_path() -> PathBuf {
+        let home = std::env::var("HOME").unwrap_or_default();
+        PathBuf::from(home)
+            .join("Library/LaunchAgents")
+            .join(format!("{}.plist", SERVICE_LABEL))
+    }
+
+    #[cfg(target_os = "macos")]
+    fn install_blocking(binary_path: &str, log_path: &std::path::Path) -> Result<(), String> {
+        let plist_path = Self::launch_agent_path();
+        let plist = format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+    <key>Label</key>
+    <string>{label}</string>
+    <key>ProgramArguments</key>
+    <array>
+        <string>{binary}</string>
+    </array>
+    <key>RunAtLoad</key>
+    <true/>
+    <key>StandardOutPath</key>
+    <string>{log}</string>
+    <key>StandardErrorPath</key>
+    <string>{log}</string>
+</dict>
+</plist>
+"#,
+            label = SERVICE_LABEL,
+            binary = binary_path,
+            log = log_path.display(),
+        );
+
+        if let Some(parent) = plist_path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        std::fs::write(&plist_path, plist).map_err(|e| e.to_string())?;
+
+        Self::run("launchctl", &["load", "-w", &plist_path.display().to_string()])
+    }
+
+    #[cfg(target_os = "macos")]
+    fn uninstall_blocking() -> Result<(), String> {
+        let plist_path = Self::launch_agent_path();
+        let _ = Self::run("launchctl", &["unload", "-w", &plist_path.display().to_string()]);
+        if plist_path.exists() {
+            std::fs::remove_file(&plist_path).map_err(|e| e.to_string())?;
+        }
+        Ok(())
+    }
+
+    #[cfg(target_os = "macos")]
+    fn start_blocking() -> Result<(), String> {
+        Self::run("launchctl", &["start", SERVICE_LABEL])
+    }
+
+    #[cfg(target_os = "macos")]
+    fn stop_blocking() -> Result<(), String> {
+        Self::run("launchctl", &["stop", SERVICE_LABEL])
+    }
+
+    #[cfg(target_os = "linux")]
+    fn unit_name() -> String {
+        format!("{}.service", SERVICE_LABEL.replace('.', "-"))
+    }
+
+    #[cfg(target_os = "linux")]
+    fn unit_path() -> PathBuf {
+        let home = std::env::var("HOME").unwrap_or_default();
+        PathBuf::from(home)
+            .join(".config/systemd/user")
+            .join(Self::unit_name())
+    }
+
+    #[cfg(target_os = "linux")]
+    fn install_blocking(binary_path: &str, _log_path: &std::path::Path) -> Result<(), String> {
+        let unit_path = Self::unit_path();
+        let unit = format!(
+            "[Unit]\nDescription=VibeTunnel server\n\n[Service]\nExecStart={binary}\nRestart=on-failure\n\n[Install]\nWantedBy=default.target\n",
+            binary = binary_path,
+        );
+
+        if let Some(parent) = unit_path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        std::fs::write(&unit_path, unit).map_err(|e| e.to_string())?;
+
+        Self::run("systemctl", &["--user", "daemon-reload"])?;
+        Self::run("systemctl", &["--user", "enable", &Self::unit_name()])
+    }
+
+    #[cfg(target_os = "linux")]
+    fn uninstall_blocking() -> Result<(), String> {
+        let _ = Self::run("systemctl", &["--user", "disable", "--now", &Self::unit_name()]);
+        let unit_path = Self::unit_path();
+        if unit_path.exists() {
+            std::fs::remove_file(&unit_path).map_err(|e| e.to_string())?;
+        }
+        Self::run("systemctl", &["--user", "daemon-reload"])
+    }
+
+    #[cfg(target_os = "linux")]
+    fn start_blocking() -> Result<(), String> {
+        Self::run("systemctl", &["--user", "start", &Self::unit_name()])
+    }
+
+    #[cfg(target_os = "linux")]
+    fn stop_blocking() -> Result<(), String> {
+        Self::run("systemctl", &["--user", "stop", &Self::unit_name()])
+    }
+
+    #[cfg(target_os = "windows")]
+    fn service_name() -> String {
+        SERVICE_LABEL.replace('.', "-")
+    }
+
+    #[cfg(target_os = "windows")]
+    fn install_blocking(binary_path: &str, log_path: &std::path::Path) -> Result<(), String> {
+        // `sc.exe` services run as SYSTEM and don't redirect stdout/stderr on
+        // their own, so the binary is expected to honor this env var and
+        // write its own log file; we just make sure the path is stable.
+        std::env::set_var("VIBETUNNEL_SERVICE_LOG", log_path);
+
+        let bin_path = format!("binPath= \"{}\"", binary_path);
+        Self::run(
+            "sc.exe",
+            &["create", &Self::service_name(), &bin_path, "start=", "auto"],
+        )
+    }
+
+    #[cfg(target_os = "windows")]
+    fn uninstall_blocking() -> Result<(), String> {
+        let _ = Self::run("sc.exe", &["stop", &Self::service_name()]);
+        Self::run("sc.exe", &["delete", &Self::service_name()])
+    }
+
+    #[cfg(target_os = "windows")]
+    fn start_blocking() -> Result<(), String> {
+        Self::run("sc.exe", &["start", &Self::service_name()])
+    }
+
+    #[cfg(target_os = "windows")]
+    fn stop_blocking() -> Result<(), String> {
+        Self::run("sc.exe", &["stop", &Self::service_name()])
+    }
+
+    /// Stream the service's log output as an async channel of lines.
+    ///
+    /// On Linux this delegates to `journalctl --user -u <unit> -f`. On
+    /// macOS/Windows, where the service writes to a plain log file, this
+    /// polls the file's size on an interval and emits newly-appended bytes
+    /// as lines — cheap enough for a single file and avoids pulling in an
+    /// inotify/kqueue dependency.
+    pub fn follow_logs(&self) -> mpsc::Receiver<String> {
+        let (tx, rx) = mpsc::channel(256);
+
+        #[cfg(target_os = "linux")]
+        {
+            tokio::spawn(async move {
+                let unit = Self::unit_name();
+                let mut command = tokio::process::Command::new("journalctl");
+                command.args(["--user", "-u", &unit, "-f", "-n", "0"]).stdout(Stdio::piped());
+                for (key, value) in crate::linux_env::sanitized_env() {
+                    if value.is_empty() {
+                        command.env_remove(&key);
+                    } else {
+                        command.env(&key, &value);
+                    }
+                }
+                let child = command.spawn();
+
+                let mut child = match child {
+                    Ok(child) => child,
+                    Err(e) => {
+                        error!("Failed to spawn journalctl: {}", e);
+                        return;
+                    }
+                };
+
+                let Some(stdout) = child.stdout.take() else {
+                    return;
+                };
+                let mut lines = BufReader::new(stdout).lines();
+                while let Ok(Some(line)) = lines.next_line().await {
+                    if tx.send(line).await.is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+
+        #[cfg(not(target_os = "linux"))]
+        {
+            let log_path = Self::log_path();
+            tokio::spawn(async move {
+                let mut offset: u64 = std::fs::metadata(&log_path).map(|m| m.len()).unwrap_or(0);
+                let mut interval = tokio::time::interval(Duration::from_millis(750));
+
+                loop {
+                    interval.tick().await;
+
+                    let Ok(metadata) = std::fs::metadata(&log_path) else {
+                        continue;
+                    };
+                    let len = metadata.len();
+                    if len <= offset {
+                        continue;
+                    }
+
+                    let Ok(mut file) = tokio::fs::File::open(&log_path).await else {
+                        continue;
+                    };
+                    if file.seek(std::io::SeekFrom::Start(offset)).await.is_err() {
+                        continue;
+                    }
+
+                    let mut buf = Vec::new();
+                    if file.read_to_end(&mut buf).await.is_err() {
+                        continue;
+                    }
+                    offset = len;
+
+                    for line in String::from_utf8_lossy(&buf).lines() {
+                        if tx.send(line.to_string()).await.is_err() {
+                            return;
+                        }
+                    }
+                }
+            });
+        }
+
+        rx
+    }
+}
+
+#[tauri::command]
+pub async fn install_tunnel_service(
+    state: tauri::State<'_, crate::state::AppState>,
+    binary_path: String,
+) -> Result<(), String> {
+    state.service_manager.install(binary_path).await
+}
+
+#[tauri::command]
+pub async fn uninstall_tunnel_service(state: tauri::State<'_, crate::state::AppState>) -> Result<(), String> {
+    state.service_manager.uninstall().await
+}
+
+#[tauri::command]
+pub async fn start_tunnel_service(state: tauri::State<'_, crate::state::AppState>) -> Result<(), String> {
+    state.service_manager.start().await
+}
+
+#[tauri::command]
+pub async fn stop_tunnel_service(state: tauri::State<'_, crate::state::AppState>) -> Result<(), String> {
+    state.service_manager.stop().await
+}
+
+#[tauri::command]
+pub async fn get_tunnel_service_status(
+    state: tauri::State<'_, crate::state::AppState>,
+) -> Result<ServiceStatus, String> {
+    Ok(state.service_manager.status().await)
+}
@@ -0,0 +1,185 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
+use tokio::sync::RwLock;
+use tracing::{error, info, warn};
+
+use crate::applescript::AppleScriptTerminalLauncher;
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
+
+/// An action a global hotkey can be bound to.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "type")]
+pub enum ShortcutAction {
+    FocusLastSession,
+    LaunchNewTerminalSession,
+    ToggleDockVisibility,
+}
+
+/// A single configured hotkey binding, e.g. `"cmd+shift+v"` -> `FocusLastSession`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShortcutBinding {
+    pub name: String,
+    pub accelerator: String,
+    pub action: ShortcutAction,
+}
+
+/// The result of attempting to register one binding.
+#[derive(Debug, Clone, Serialize)]
+pub struct ShortcutRegistrationError {
+    pub name: String,
+    pub accelerator: String,
+    pub message: String,
+}
+
+/// Manages registration of configurable global hotkeys and dispatches their
+/// bound actions into the existing terminal-launcher / dock-manager paths.
+pub struct ShortcutManager {
+    bindings: Arc<RwLock<HashMap<String, ShortcutBinding>>>,
+    last_session_id: Arc<RwLock<Option<String>>>,
+}
+
+impl ShortcutManager {
+    pub fn new() -> Self {
+        Self {
+            bindings: Arc::new(RwLock::new(HashMap::new())),
+            last_session_id: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    /// Remember the most recently active session so `FocusLastSession` has a target.
+    pub async fn set_last_session(&self, session_id: String) {
+        *self.last_session_id.write().await = Some(session_id);
+    }
+
+    /// Register every binding from app config. Failures are collected
+    /// per-binding rather than aborting the whole set.
+    pub async fn register_all(
+        &self,
+        app: &AppHandle,
+        bindings: Vec<ShortcutBinding>,
+    ) -> Vec<ShortcutRegistrationError> {
+        let mut errors = Vec::new();
+        let mut registered = HashMap::new();
+
+        for binding in bindings {
+            match Self::register_one(app, &binding) {
+                Ok(()) => {
+                    info!("Registered shortcut '{}' ({})", binding.name, binding.accelerator);
+                    registered.insert(binding.name.clone(), binding);
+                }
+                Err(message) => {
+                    error!("Failed to register shortcut '{}': {}", binding.name, message);
+                    errors.push(ShortcutRegistrationError {
+                        name: binding.name.clone(),
+                        accelerator: binding.accelerator.clone(),
+                        message,
+                    });
+                }
+            }
+        }
+
+        *self.bindings.write().await = registered;
+        errors
+    }
+
+    fn register_one(app: &AppHandle, binding: &ShortcutBinding) -> Result<(), String> {
+        if binding.accelerator.trim().is_empty() {
+            return Err("Accelerator string is empty".to_string());
+        }
+
+        // Validate the accelerator syntax before handing it to the plugin so a
+        // single malformed binding produces a useful per-binding error instead
+        // of a generic parse failure.
+        if !binding.accelerator.contains('+') {
+            return Err(format!("'{}' does not look like a valid accelerator", binding.accelerator));
+        }
+
+        let shortcut: Shortcut = binding
+            .accelerator
+            .parse()
+            .map_err(|e| format!("Failed to parse accelerator '{}': {}", binding.accelerator, e))?;
+
+        let name = binding.name.clone();
+        app.global_shortcut()
+            .on_shortcut(shortcut, move |app_handle, _shortcut, event| {
+                if event.state() != ShortcutState::Pressed {
+                    return;
+                }
+
+                let app_handle = app_handle.clone();
+                let name = name.clone();
+                tauri::async_runtime::spawn(async move {
+                    let state = app_handle.state::<crate::state::AppState>();
+                    if let Err(e) = state.shortcut_manager.invoke(&app_handle, &name).await {
+                        error!("Failed to invoke shortcut '{}': {}", name, e);
+                    }
+                });
+            })
+            .map_err(|e| format!("Failed to register accelerator '{}': {}", binding.accelerator, e))
+    }
+
+    /// Invoke a bound action by shortcut name (used by both the hotkey
+    /// callback and the `invoke_shortcut` command).
+    pub async fn invoke(&self, app: &AppHandle, name: &str) -> Result<(), String> {
+        let action = {
+            let bindings = self.bindings.read().await;
+            bindings
+                .get(name)
+                .map(|b| b.action.clone())
+                .ok_or_else(|| format!("No shortcut named '{}' is registered", name))?
+        };
+
+        self.run_action(app, &action).await
+    }
+
+    async fn run_action(&self, app: &AppHandle, action: &ShortcutAction) -> Result<(), String> {
+        match action {
+            ShortcutAction::FocusLastSession => {
+                let session_id = self.last_session_id.read().await.clone();
+                match session_id {
+                    Some(session_id) => {
+                        AppleScriptTerminalLauncher::focus_terminal_window("Terminal", &session_id).await
+                    }
+                    None => {
+                        warn!("FocusLastSession invoked with no known session");
+                        Err("No session to focus".to_string())
+                    }
+                }
+            }
+            ShortcutAction::LaunchNewTerminalSession => {
+                AppleScriptTerminalLauncher::launch_terminal("Terminal", "new", None, None)
+                    .await
+                    .map(|_| ())
+            }
+            ShortcutAction::ToggleDockVisibility => {
+                let state = app.state::<crate::state::AppState>();
+                let currently_shown = state.dock_manager.get_show_in_dock();
+                state.dock_manager.set_show_in_dock(!currently_shown);
+                if let Some(app_handle) = state.get_app_handle() {
+                    state.dock_manager.update_dock_visibility(&app_handle);
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn register_shortcuts(
+    app: AppHandle,
+    state: tauri::State<'_, crate::state::AppState>,
+    bindings: Vec<ShortcutBinding>,
+) -> Result<Vec<ShortcutRegistrationError>, String> {
+    Ok(state.shortcut_manager.register_all(&app, bindings).await)
+}
+
+#[tauri::command]
+pub async fn invoke_shortcut(
+    app: AppHandle,
+    state: tauri::State<'_, crate::state::AppState>,
+    name: String,
+) -> Result<(), String> {
+    state.shortcut_manager.invoke(&app, &name).await
+}
@@ -1,16 +1,78 @@
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
 use tauri::{AppHandle, Manager};
 use tracing::{debug, error};
 
+/// Minimum time between two `animate_activity` triggers; a trigger arriving
+/// inside this window is dropped and the currently-playing animation is left
+/// as it is.
+const ANIMATION_DEBOUNCE: Duration = Duration::from_millis(150);
+
+/// An ordered sequence of tray icon frame names, played at a fixed interval
+/// by a cancellable `tokio` task.
+#[derive(Debug, Clone)]
+pub struct AnimationSequence {
+    pub frames: Vec<String>,
+    pub frame_interval: Duration,
+}
+
+impl AnimationSequence {
+    /// Build a sequence from a `tray-icon-activity-00..NN.png`-style prefix.
+    fn numbered(prefix: &str, count: usize, frame_interval_ms: u64) -> Self {
+        Self {
+            frames: (0..count).map(|i| format!("{}-{:02}", prefix, i)).collect(),
+            frame_interval: Duration::from_millis(frame_interval_ms),
+        }
+    }
+}
+
 /// Visual status indicators for the tray icon
 pub struct StatusIndicator {
-    app_handle: Arc<std::sync::Mutex<Option<AppHandle>>>,
+    app_handle: Arc<Mutex<Option<AppHandle>>>,
+    sequences: RwLock<HashMap<String, AnimationSequence>>,
+    active_animation: Mutex<Option<tokio::task::JoinHandle<()>>>,
+    last_status: Mutex<(bool, usize, usize)>,
+    last_triggered: Mutex<Option<Instant>>,
 }
 
 impl StatusIndicator {
     pub fn new() -> Self {
+        let mut sequences = HashMap::new();
+        sequences.insert(
+            "activity".to_string(),
+            AnimationSequence::numbered("tray-icon-activity", 8, 120),
+        );
+
         Self {
-            app_handle: Arc::new(std::sync::Mutex::new(None)),
+            app_handle: Arc::new(Mutex::new(None)),
+            sequences: RwLock::new(sequences),
+            active_animation: Mutex::new(None),
+            last_status: Mutex::new((false, 0, 0)),
+            last_triggered: Mutex::new(None),
+        }
+    }
+
+    /// Register (or replace) a named animation sequence so callers can drive
+    /// `animate_sequence` with something other than the default "activity"
+    /// spinner.
+    pub fn register_sequence(&self, name: &str, sequence: AnimationSequence) {
+        self.sequences.write().unwrap().insert(name.to_string(), sequence);
+    }
+
+    /// The steady-state icon name for a given status, shared by
+    /// `update_tray_icon` and the animation's restore step.
+    fn steady_icon_name(server_running: bool, active_sessions: usize, total_sessions: usize) -> &'static str {
+        if server_running {
+            if active_sessions > 0 {
+                "tray-icon-active"
+            } else if total_sessions > 0 {
+                "tray-icon-idle"
+            } else {
+                "tray-icon"
+            }
+        } else {
+            "tray-icon-inactive"
         }
     }
 
@@ -22,21 +84,13 @@ impl StatusIndicator {
 
     /// Update the tray icon based on server and session status
     pub fn update_tray_icon(&self, server_running: bool, active_sessions: usize, total_sessions: usize) {
+        *self.last_status.lock().unwrap() = (server_running, active_sessions, total_sessions);
+
         let guard = self.app_handle.lock().unwrap();
         if let Some(app_handle) = guard.as_ref() {
             if let Some(tray) = app_handle.tray_by_id("main") {
                 // Update icon based on status
-                let icon_name = if server_running {
-                    if active_sessions > 0 {
-                        "tray-icon-active" // Green/active indicator
-                    } else if total_sessions > 0 {
-                        "tray-icon-idle" // Yellow/idle indicator
-                    } else {
-                        "tray-icon" // Normal running state
-                    }
-                } else {
-                    "tray-icon-inactive" // Gray/inactive state
-                };
+                let icon_name = Self::steady_icon_name(server_running, active_sessions, total_sessions);
 
                 // Try to load the appropriate icon
                 match Self::load_icon_data(app_handle, icon_name) {
@@ -123,33 +177,75 @@ impl StatusIndicator {
         Err(format!("Icon {} not found", name))
     }
 
-    /// Animate the tray icon for notifications or activity
+    /// Animate the tray icon for notifications or activity, using the
+    /// default "activity" frame sequence.
     pub async fn animate_activity(&self) {
-        // Simple animation: briefly change icon to indicate activity
+        self.animate_sequence("activity").await;
+    }
+
+    /// Play a named animation sequence once. To throttle bursts of session
+    /// I/O, a call within `ANIMATION_DEBOUNCE` of the last one is dropped
+    /// entirely rather than queued or merged; a call outside that window
+    /// aborts whatever sequence is currently playing and starts this one
+    /// from its first frame. The steady-state icon (active/idle/inactive,
+    /// per `update_tray_icon`) is restored once the sequence finishes.
+    pub async fn animate_sequence(&self, name: &str) {
+        {
+            let mut last_triggered = self.last_triggered.lock().unwrap();
+            if let Some(last) = *last_triggered {
+                if last.elapsed() < ANIMATION_DEBOUNCE {
+                    return;
+                }
+            }
+            *last_triggered = Some(Instant::now());
+        }
+
+        let sequence = match self.sequences.read().unwrap().get(name).cloned() {
+            Some(sequence) if !sequence.frames.is_empty() => sequence,
+            _ => {
+                debug!("No animation sequence registered for '{}'", name);
+                return;
+            }
+        };
+
         let app_handle = {
             let guard = self.app_handle.lock().unwrap();
             guard.clone()
         };
-        
-        if let Some(app_handle) = app_handle {
-            if let Some(tray) = app_handle.tray_by_id("main") {
-                // Flash the icon by changing it briefly
-                if let Ok(active_icon_data) = Self::load_icon_data(&app_handle, "tray-icon-flash") {
-                    if let Ok(active_image) = tauri::image::Image::from_bytes(&active_icon_data) {
-                        let _ = tray.set_icon(Some(active_image));
-                        
-                        // Restore after a short delay
-                        tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
-                        
-                        if let Ok(normal_icon_data) = Self::load_icon_data(&app_handle, "tray-icon") {
-                            if let Ok(normal_image) = tauri::image::Image::from_bytes(&normal_icon_data) {
-                                let _ = tray.set_icon(Some(normal_image));
-                            }
-                        }
+        let Some(app_handle) = app_handle else {
+            return;
+        };
+
+        // Cancel any animation already in flight before starting a new one.
+        if let Some(previous) = self.active_animation.lock().unwrap().take() {
+            previous.abort();
+        }
+
+        let last_status = *self.last_status.lock().unwrap();
+        let handle = tokio::spawn(async move {
+            let Some(tray) = app_handle.tray_by_id("main") else {
+                return;
+            };
+
+            for frame_name in &sequence.frames {
+                if let Ok(frame_data) = Self::load_icon_data(&app_handle, frame_name) {
+                    if let Ok(frame_image) = tauri::image::Image::from_bytes(&frame_data) {
+                        let _ = tray.set_icon(Some(frame_image));
                     }
                 }
+                tokio::time::sleep(sequence.frame_interval).await;
             }
-        }
+
+            let (server_running, active_sessions, total_sessions) = last_status;
+            let icon_name = Self::steady_icon_name(server_running, active_sessions, total_sessions);
+            if let Ok(icon_data) = Self::load_icon_data(&app_handle, icon_name) {
+                if let Ok(image) = tauri::image::Image::from_bytes(&icon_data) {
+                    let _ = tray.set_icon(Some(image));
+                }
+            }
+        });
+
+        *self.active_animation.lock().unwrap() = Some(handle);
     }
 }
 
@@ -10,6 +10,17 @@ pub struct TailscaleStatus {
     pub hostname: Option<String>,
     pub ip_address: Option<String>,
     pub status_error: Option<String>,
+    /// MagicDNS URL the running terminal server is currently published at via
+    /// `tailscale serve`/`tailscale funnel`, if any.
+    pub serve_url: Option<String>,
+}
+
+/// The subset of `tailscale serve status --json` this crate cares about:
+/// which MagicDNS host:port pairs are currently being served.
+#[derive(Debug, Deserialize)]
+struct ServeStatusResponse {
+    #[serde(rename = "Web", default)]
+    web: std::collections::HashMap<String, serde_json::Value>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -22,6 +33,41 @@ struct TailscaleAPIResponse {
     ipv4: Option<String>,
 }
 
+/// One other device reachable on the tailnet, for a device picker.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TailscalePeer {
+    pub hostname: String,
+    pub magic_dns_name: Option<String>,
+    pub tailscale_ips: Vec<String>,
+    pub os: Option<String>,
+    pub online: bool,
+    pub last_seen: Option<String>,
+}
+
+/// One entry in the `Peer` map of `tailscale status --json`.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct TailscalePeerEntry {
+    host_name: String,
+    #[serde(rename = "DNSName")]
+    dns_name: Option<String>,
+    #[serde(rename = "TailscaleIPs", default)]
+    tailscale_ips: Vec<String>,
+    #[serde(rename = "OS")]
+    os: Option<String>,
+    #[serde(default)]
+    online: bool,
+    last_seen: Option<String>,
+}
+
+/// The subset of `tailscale status --json` used by `get_peers`.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct TailscaleStatusJson {
+    #[serde(rename = "Peer", default)]
+    peer: std::collections::HashMap<String, TailscalePeerEntry>,
+}
+
 pub struct TailscaleService {
     status: Arc<RwLock<TailscaleStatus>>,
 }
@@ -38,6 +84,7 @@ impl TailscaleService {
                 hostname: None,
                 ip_address: None,
                 status_error: None,
+                serve_url: None,
             })),
         }
     }
@@ -94,6 +141,7 @@ impl TailscaleService {
                 hostname: None,
                 ip_address: None,
                 status_error: Some("Tailscale is not installed".to_string()),
+                serve_url: None,
             };
             *self.status.write().await = status.clone();
             return status;
@@ -120,12 +168,14 @@ impl TailscaleService {
                     (None, None, Some("Tailscale is not running".to_string()))
                 };
 
+                let previous_serve_url = self.status.read().await.serve_url.clone();
                 let status = TailscaleStatus {
                     is_installed,
                     is_running,
                     hostname,
                     ip_address,
                     status_error,
+                    serve_url: previous_serve_url,
                 };
                 *self.status.write().await = status.clone();
                 status
@@ -138,6 +188,7 @@ impl TailscaleService {
                     hostname: None,
                     ip_address: None,
                     status_error: Some("Please start the Tailscale app".to_string()),
+                    serve_url: None,
                 };
                 *self.status.write().await = status.clone();
                 status
@@ -145,6 +196,37 @@ impl TailscaleService {
         }
     }
 
+    /// Enumerate reachable tailnet peers via `tailscale status --json`, for a
+    /// device picker. Falls back to an empty list when the CLI isn't
+    /// installed — the `100.100.100.100` local API path has no peer data,
+    /// so there's nothing further to fall back to for peer enumeration.
+    pub async fn get_peers(&self) -> Result<Vec<TailscalePeer>, String> {
+        let Ok(binary) = Self::tailscale_binary_path() else {
+            return Ok(Vec::new());
+        };
+        let output = tokio::task::spawn_blocking(move || {
+            Self::run_tailscale(&binary, &["status".to_string(), "--json".to_string()])
+        })
+        .await
+        .map_err(|e| format!("tailscale status task panicked: {}", e))??;
+
+        let parsed: TailscaleStatusJson =
+            serde_json::from_str(&output).map_err(|e| format!("Failed to parse tailscale status: {}", e))?;
+
+        Ok(parsed
+            .peer
+            .into_values()
+            .map(|entry| TailscalePeer {
+                hostname: entry.host_name,
+                magic_dns_name: entry.dns_name.map(|name| name.trim_end_matches('.').to_string()),
+                tailscale_ips: entry.tailscale_ips,
+                os: entry.os,
+                online: entry.online,
+                last_seen: entry.last_seen,
+            })
+            .collect())
+    }
+
     /// Start monitoring Tailscale status
     pub async fn start_monitoring(&self) {
         let status = self.status.clone();
@@ -170,10 +252,10 @@ impl TailscaleService {
         #[cfg(target_os = "linux")]
         {
             // Try to launch via desktop file or command
-            std::process::Command::new("tailscale")
-                .arg("up")
-                .spawn()
-                .map_err(|e| format!("Failed to start Tailscale: {}", e))?;
+            let mut command = std::process::Command::new("tailscale");
+            command.arg("up");
+            crate::linux_env::apply_to_command(&mut command);
+            command.spawn().map_err(|e| format!("Failed to start Tailscale: {}", e))?;
             Ok(())
         }
         #[cfg(target_os = "windows")]
@@ -202,4 +284,143 @@ impl TailscaleService {
         open::that("https://tailscale.com/kb/1017/install/")
             .map_err(|e| format!("Failed to open setup guide: {}", e))
     }
+
+    /// Locate the `tailscale` CLI binary, mirroring `check_app_installation`'s
+    /// per-platform install paths.
+    fn tailscale_binary_path() -> Result<String, String> {
+        #[cfg(target_os = "macos")]
+        let candidates = [
+            "/Applications/Tailscale.app/Contents/MacOS/Tailscale",
+            "/usr/local/bin/tailscale",
+            "/opt/homebrew/bin/tailscale",
+        ];
+        #[cfg(target_os = "linux")]
+        let candidates = ["/usr/bin/tailscale", "/usr/local/bin/tailscale", "/opt/tailscale/tailscale"];
+        #[cfg(target_os = "windows")]
+        let candidates = [
+            "C:\\Program Files\\Tailscale\\tailscale.exe",
+            "C:\\Program Files (x86)\\Tailscale\\tailscale.exe",
+        ];
+
+        candidates
+            .into_iter()
+            .find(|path| std::path::Path::new(path).exists())
+            .map(|path| path.to_string())
+            .ok_or_else(|| "Tailscale CLI not found".to_string())
+    }
+
+    /// Run a `tailscale` subcommand and return its trimmed stdout.
+    fn run_tailscale(binary: &str, args: &[String]) -> Result<String, String> {
+        let mut command = std::process::Command::new(binary);
+        command.args(args);
+        #[cfg(target_os = "linux")]
+        crate::linux_env::apply_to_command(&mut command);
+
+        let output = command
+            .output()
+            .map_err(|e| format!("Failed to run tailscale {}: {}", args.join(" "), e))?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+
+    /// Read the MagicDNS host(s) currently published by `tailscale serve`.
+    async fn resolve_serve_url() -> Result<String, String> {
+        let binary = Self::tailscale_binary_path()?;
+        let output = tokio::task::spawn_blocking(move || {
+            Self::run_tailscale(&binary, &["serve".to_string(), "status".to_string(), "--json".to_string()])
+        })
+        .await
+        .map_err(|e| format!("tailscale serve status task panicked: {}", e))??;
+
+        let parsed: ServeStatusResponse = serde_json::from_str(&output)
+            .map_err(|e| format!("Failed to parse tailscale serve status: {}", e))?;
+
+        parsed
+            .web
+            .keys()
+            .next()
+            .map(|host_port| format!("https://{}", host_port.split(':').next().unwrap_or(host_port)))
+            .ok_or_else(|| "tailscale serve is not publishing any address".to_string())
+    }
+
+    /// Publish `port` (optionally under `path`) over the tailnet via
+    /// `tailscale serve --bg`, returning the resulting MagicDNS URL.
+    pub async fn serve(&self, port: u16, path: Option<String>) -> Result<String, String> {
+        let binary = Self::tailscale_binary_path()?;
+        let mut args = vec!["serve".to_string(), "--bg".to_string()];
+        if let Some(path) = path {
+            args.push(format!("--set-path={}", path));
+        }
+        args.push("https".to_string());
+        args.push(port.to_string());
+
+        tokio::task::spawn_blocking(move || Self::run_tailscale(&binary, &args))
+            .await
+            .map_err(|e| format!("tailscale serve task panicked: {}", e))??;
+
+        let url = Self::resolve_serve_url().await?;
+        self.status.write().await.serve_url = Some(url.clone());
+        Ok(url)
+    }
+
+    /// Publish `port` publicly over HTTPS via `tailscale funnel --bg`,
+    /// returning the resulting public URL.
+    pub async fn funnel(&self, port: u16) -> Result<String, String> {
+        let binary = Self::tailscale_binary_path()?;
+        let args = vec!["funnel".to_string(), "--bg".to_string(), port.to_string()];
+
+        tokio::task::spawn_blocking(move || Self::run_tailscale(&binary, &args))
+            .await
+            .map_err(|e| format!("tailscale funnel task panicked: {}", e))??;
+
+        let url = Self::resolve_serve_url().await?;
+        self.status.write().await.serve_url = Some(url.clone());
+        Ok(url)
+    }
+
+    /// Tear down any `serve`/`funnel` publication made through this service.
+    pub async fn reset_serve(&self) -> Result<(), String> {
+        let binary = Self::tailscale_binary_path()?;
+        tokio::task::spawn_blocking(move || {
+            Self::run_tailscale(&binary, &["serve".to_string(), "reset".to_string()])
+        })
+        .await
+        .map_err(|e| format!("tailscale serve reset task panicked: {}", e))??;
+
+        self.status.write().await.serve_url = None;
+        Ok(())
+    }
+}
+
+#[tauri::command]
+pub async fn tailscale_serve(
+    state: tauri::State<'_, crate::state::AppState>,
+    port: u16,
+    path: Option<String>,
+) -> Result<String, String> {
+    state.tailscale_service.serve(port, path).await
+}
+
+#[tauri::command]
+pub async fn tailscale_funnel(
+    state: tauri::State<'_, crate::state::AppState>,
+    port: u16,
+) -> Result<String, String> {
+    state.tailscale_service.funnel(port).await
+}
+
+#[tauri::command]
+pub async fn tailscale_reset_serve(state: tauri::State<'_, crate::state::AppState>) -> Result<(), String> {
+    state.tailscale_service.reset_serve().await
+}
+
+#[tauri::command]
+pub async fn tailscale_get_peers(
+    state: tauri::State<'_, crate::state::AppState>,
+) -> Result<Vec<TailscalePeer>, String> {
+    state.tailscale_service.get_peers().await
 }
\ No newline at end of file
@@ -0,0 +1,106 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::applescript::AppleScriptTerminalLauncher;
+use crate::git_repository::GitRepository;
+
+/// A single named runnable read from a `vibetunnel.tasks.json` file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskDefinition {
+    pub label: String,
+    pub command: String,
+    #[serde(default)]
+    pub cwd: Option<String>,
+    #[serde(default)]
+    pub env: HashMap<String, String>,
+}
+
+/// The contents of a `vibetunnel.tasks.json` file.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct TaskFile {
+    #[serde(default)]
+    pub tasks: Vec<TaskDefinition>,
+}
+
+pub const TASKS_FILE_NAME: &str = "vibetunnel.tasks.json";
+
+impl TaskFile {
+    /// Load `vibetunnel.tasks.json` from a directory, if present.
+    pub fn load(dir: &Path) -> Result<Self, String> {
+        let path = dir.join(TASKS_FILE_NAME);
+        let contents = std::fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        serde_json::from_str(&contents).map_err(|e| format!("Failed to parse {}: {}", path.display(), e))
+    }
+
+    /// Load the tasks file relative to a tracked Git repository, returning an
+    /// empty task list when the repo has no tasks file rather than erroring.
+    pub fn load_for_repository(repo: &GitRepository) -> Self {
+        let dir = PathBuf::from(&repo.path);
+        Self::load(&dir).unwrap_or_default()
+    }
+
+    pub fn find(&self, label: &str) -> Option<&TaskDefinition> {
+        self.tasks.iter().find(|t| t.label == label)
+    }
+}
+
+impl TaskDefinition {
+    /// Resolve this task's working directory relative to the repository root
+    /// it was discovered in, falling back to the repository root itself.
+    pub fn resolved_cwd(&self, repo: &GitRepository) -> String {
+        match &self.cwd {
+            Some(cwd) => PathBuf::from(&repo.path).join(cwd).to_string_lossy().into_owned(),
+            None => repo.path.clone(),
+        }
+    }
+
+    /// Compose the shell command including any task-specific env assignments.
+    fn shell_command(&self) -> String {
+        if self.env.is_empty() {
+            return self.command.clone();
+        }
+
+        let assignments = self
+            .env
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, shell_escape(v)))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        format!("{} {}", assignments, self.command)
+    }
+
+    /// Spawn this task into a new terminal session, tracked like any other
+    /// VibeTunnel session.
+    pub async fn spawn(&self, repo: &GitRepository, session_id: &str) -> Result<String, String> {
+        let cwd = self.resolved_cwd(repo);
+        AppleScriptTerminalLauncher::launch_terminal(
+            "Terminal",
+            session_id,
+            Some(&self.shell_command()),
+            Some(&cwd),
+        )
+        .await
+    }
+}
+
+fn shell_escape(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+#[tauri::command]
+pub fn list_repository_tasks(repo_path: String) -> Vec<TaskDefinition> {
+    TaskFile::load_for_repository(&GitRepository::new(repo_path)).tasks
+}
+
+#[tauri::command]
+pub async fn spawn_repository_task(repo_path: String, label: String, session_id: String) -> Result<String, String> {
+    let repo = GitRepository::new(repo_path);
+    let task_file = TaskFile::load_for_repository(&repo);
+    let task = task_file
+        .find(&label)
+        .ok_or_else(|| format!("No task named '{}' found for repository", label))?;
+    task.spawn(&repo, &session_id).await
+}
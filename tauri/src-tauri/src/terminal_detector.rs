@@ -0,0 +1,141 @@
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// How a detected terminal emulator should be launched.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum LaunchStrategy {
+    /// Drive the terminal via AppleScript (Terminal.app / iTerm2).
+    AppleScript,
+    /// Invoke a CLI binary directly, e.g. `wezterm start` or `alacritty -e`.
+    Cli { binary: String, args: Vec<String> },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DetectedTerminal {
+    pub name: String,
+    pub path: String,
+    pub strategy: LaunchStrategy,
+    pub is_running: bool,
+}
+
+/// One known terminal emulator and the fixed locations it's typically
+/// installed at, mirroring the way Homebrew is probed at its two known
+/// Intel (`/usr/local`) and ARM (`/opt/homebrew`) prefixes rather than
+/// searched for generically.
+struct TerminalCandidate {
+    name: &'static str,
+    app_bundle_paths: &'static [&'static str],
+    cli_paths: &'static [&'static str],
+    strategy: fn() -> LaunchStrategy,
+}
+
+const CANDIDATES: &[TerminalCandidate] = &[
+    TerminalCandidate {
+        name: "Terminal",
+        app_bundle_paths: &["/System/Applications/Utilities/Terminal.app"],
+        cli_paths: &[],
+        strategy: || LaunchStrategy::AppleScript,
+    },
+    TerminalCandidate {
+        name: "iTerm2",
+        app_bundle_paths: &["/Applications/iTerm.app"],
+        cli_paths: &[],
+        strategy: || LaunchStrategy::AppleScript,
+    },
+    TerminalCandidate {
+        name: "Ghostty",
+        app_bundle_paths: &["/Applications/Ghostty.app"],
+        cli_paths: &["/opt/homebrew/bin/ghostty", "/usr/local/bin/ghostty"],
+        strategy: || LaunchStrategy::Cli {
+            binary: "ghostty".to_string(),
+            args: vec!["-e".to_string()],
+        },
+    },
+    TerminalCandidate {
+        name: "WezTerm",
+        app_bundle_paths: &["/Applications/WezTerm.app"],
+        cli_paths: &["/opt/homebrew/bin/wezterm", "/usr/local/bin/wezterm"],
+        strategy: || LaunchStrategy::Cli {
+            binary: "wezterm".to_string(),
+            args: vec!["start".to_string()],
+        },
+    },
+    TerminalCandidate {
+        name: "Alacritty",
+        app_bundle_paths: &["/Applications/Alacritty.app"],
+        cli_paths: &["/opt/homebrew/bin/alacritty", "/usr/local/bin/alacritty"],
+        strategy: || LaunchStrategy::Cli {
+            binary: "alacritty".to_string(),
+            args: vec!["-e".to_string()],
+        },
+    },
+    TerminalCandidate {
+        name: "kitty",
+        app_bundle_paths: &["/Applications/kitty.app"],
+        cli_paths: &["/opt/homebrew/bin/kitty", "/usr/local/bin/kitty"],
+        strategy: || LaunchStrategy::Cli {
+            binary: "kitty".to_string(),
+            args: vec![],
+        },
+    },
+];
+
+/// Detects installed terminal emulators so the UI can present only what's
+/// actually available, and so `AppleScriptTerminalLauncher` can pick the
+/// right invocation for terminals it can't drive via AppleScript.
+pub struct TerminalDetector;
+
+impl TerminalDetector {
+    /// Enumerate every installed terminal emulator known to this registry.
+    pub fn detect_all() -> Vec<DetectedTerminal> {
+        CANDIDATES
+            .iter()
+            .filter_map(Self::detect_one)
+            .collect()
+    }
+
+    /// Look up a single terminal by name (as used elsewhere as `terminal_type`).
+    pub fn find(name: &str) -> Option<DetectedTerminal> {
+        CANDIDATES
+            .iter()
+            .find(|c| c.name.eq_ignore_ascii_case(name))
+            .and_then(Self::detect_one)
+    }
+
+    fn detect_one(candidate: &TerminalCandidate) -> Option<DetectedTerminal> {
+        if let Some(app_path) = candidate.app_bundle_paths.iter().find(|p| Path::new(p).exists()) {
+            return Some(DetectedTerminal {
+                name: candidate.name.to_string(),
+                path: app_path.to_string(),
+                strategy: (candidate.strategy)(),
+                is_running: Self::is_running(candidate.name),
+            });
+        }
+
+        if let Some(cli_path) = candidate.cli_paths.iter().find(|p| Path::new(p).exists()) {
+            return Some(DetectedTerminal {
+                name: candidate.name.to_string(),
+                path: cli_path.to_string(),
+                strategy: (candidate.strategy)(),
+                is_running: Self::is_running(candidate.name),
+            });
+        }
+
+        None
+    }
+
+    #[cfg(target_os = "macos")]
+    fn is_running(name: &str) -> bool {
+        crate::applescript::AppleScriptRunner::is_app_running(name).unwrap_or(false)
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    fn is_running(_name: &str) -> bool {
+        false
+    }
+}
+
+#[tauri::command]
+pub fn get_installed_terminals() -> Vec<DetectedTerminal> {
+    TerminalDetector::detect_all()
+}
@@ -0,0 +1,173 @@
+use std::process::Command;
+use tracing::{info, warn};
+
+/// Cross-platform abstraction over "launch/focus a terminal for a session".
+///
+/// `AppleScriptTerminalLauncher` (macOS) is one implementor; Linux and Windows
+/// get their own backends since there's no AppleScript to drive there.
+#[async_trait::async_trait]
+pub trait TerminalLauncher: Send + Sync {
+    /// Launch a terminal running `command` (or a default `vt connect` invocation)
+    /// for the given session, optionally in `working_directory`.
+    async fn launch_terminal(
+        &self,
+        terminal_type: &str,
+        session_id: &str,
+        command: Option<&str>,
+        working_directory: Option<&str>,
+    ) -> Result<String, String>;
+
+    /// Focus a previously launched terminal window.
+    async fn focus_terminal_window(&self, terminal_type: &str, window_info: &str) -> Result<(), String>;
+}
+
+#[cfg(target_os = "macos")]
+#[async_trait::async_trait]
+impl TerminalLauncher for crate::applescript::AppleScriptTerminalLauncher {
+    async fn launch_terminal(
+        &self,
+        terminal_type: &str,
+        session_id: &str,
+        command: Option<&str>,
+        working_directory: Option<&str>,
+    ) -> Result<String, String> {
+        Self::launch_terminal(terminal_type, session_id, command, working_directory).await
+    }
+
+    async fn focus_terminal_window(&self, terminal_type: &str, window_info: &str) -> Result<(), String> {
+        Self::focus_terminal_window(terminal_type, window_info).await
+    }
+}
+
+/// Terminal launcher backend for Linux desktops.
+///
+/// Supports the common terminal emulators directly and sanitizes the
+/// environment handed to the child process so that VibeTunnel's own
+/// AppImage/Flatpak/Snap runtime variables don't leak into (and break)
+/// the launched terminal.
+pub struct LinuxTerminalLauncher;
+
+impl LinuxTerminalLauncher {
+    const KNOWN_TERMINALS: &'static [(&'static str, &'static [&'static str])] = &[
+        ("gnome-terminal", &["--"]),
+        ("konsole", &["-e"]),
+        ("xfce4-terminal", &["-e"]),
+        ("alacritty", &["-e"]),
+        ("kitty", &[]),
+        ("xterm", &["-e"]),
+    ];
+
+    fn default_command(session_id: &str) -> String {
+        format!("vt connect localhost:4022/{}", session_id)
+    }
+
+    fn pick_terminal() -> Option<(&'static str, &'static [&'static str])> {
+        Self::KNOWN_TERMINALS
+            .iter()
+            .copied()
+            .find(|(bin, _)| Command::new("which").arg(bin).output().map(|o| o.status.success()).unwrap_or(false))
+    }
+}
+
+#[async_trait::async_trait]
+impl TerminalLauncher for LinuxTerminalLauncher {
+    async fn launch_terminal(
+        &self,
+        _terminal_type: &str,
+        session_id: &str,
+        command: Option<&str>,
+        working_directory: Option<&str>,
+    ) -> Result<String, String> {
+        let cmd = command.map(|c| c.to_string()).unwrap_or_else(|| Self::default_command(session_id));
+        let full_command = if let Some(cwd) = working_directory {
+            format!("cd '{}' && {}", cwd, cmd)
+        } else {
+            cmd
+        };
+
+        let Some((bin, extra_args)) = Self::pick_terminal() else {
+            return Err("No supported terminal emulator found on PATH".to_string());
+        };
+
+        info!("Launching {} for session {}", bin, session_id);
+
+        let mut process = Command::new(bin);
+        process.args(extra_args);
+        process.arg("bash").arg("-c").arg(&full_command);
+        crate::linux_env::apply_to_command(&mut process);
+
+        process
+            .spawn()
+            .map(|child| child.id().to_string())
+            .map_err(|e| format!("Failed to launch {}: {}", bin, e))
+    }
+
+    async fn focus_terminal_window(&self, _terminal_type: &str, window_info: &str) -> Result<(), String> {
+        let mut process = Command::new("wmctrl");
+        process.arg("-i").arg("-a").arg(window_info);
+        crate::linux_env::apply_to_command(&mut process);
+
+        match process.output() {
+            Ok(output) if output.status.success() => Ok(()),
+            _ => {
+                warn!("wmctrl could not focus window {}", window_info);
+                Err(format!("Failed to focus window {}", window_info))
+            }
+        }
+    }
+}
+
+/// Terminal launcher backend for Windows (cmd.exe / Windows Terminal).
+pub struct WindowsTerminalLauncher;
+
+impl WindowsTerminalLauncher {
+    fn default_command(session_id: &str) -> String {
+        format!("vt connect localhost:4022/{}", session_id)
+    }
+}
+
+#[async_trait::async_trait]
+impl TerminalLauncher for WindowsTerminalLauncher {
+    async fn launch_terminal(
+        &self,
+        _terminal_type: &str,
+        session_id: &str,
+        command: Option<&str>,
+        working_directory: Option<&str>,
+    ) -> Result<String, String> {
+        let cmd = command.map(|c| c.to_string()).unwrap_or_else(|| Self::default_command(session_id));
+
+        let mut process = Command::new("wt.exe");
+        if let Some(cwd) = working_directory {
+            process.arg("-d").arg(cwd);
+        }
+        process.arg("cmd.exe").arg("/K").arg(&cmd);
+
+        info!("Launching wt.exe for session {}", session_id);
+
+        process
+            .spawn()
+            .map(|child| child.id().to_string())
+            .map_err(|e| format!("Failed to launch Windows Terminal: {}", e))
+    }
+
+    async fn focus_terminal_window(&self, _terminal_type: &str, _window_info: &str) -> Result<(), String> {
+        Err("Focusing windows is not yet implemented for the Windows terminal launcher".to_string())
+    }
+}
+
+/// Returns the platform-appropriate terminal launcher implementation.
+pub fn platform_launcher() -> Box<dyn TerminalLauncher> {
+    #[cfg(target_os = "macos")]
+    {
+        Box::new(crate::applescript::AppleScriptTerminalLauncher)
+    }
+    #[cfg(target_os = "linux")]
+    {
+        Box::new(LinuxTerminalLauncher)
+    }
+    #[cfg(target_os = "windows")]
+    {
+        Box::new(WindowsTerminalLauncher)
+    }
+}
@@ -0,0 +1,141 @@
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tauri::AppHandle;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{UnixListener, UnixStream};
+use tracing::{debug, error, info, warn};
+
+use crate::window_manager::{WindowId, WindowManager};
+
+/// Environment variable pointing at the running instance's control socket,
+/// so a second `vibetunnel msg` invocation can find and attach to it.
+pub const SOCKET_ENV_VAR: &str = "VIBETUNNEL_SOCKET";
+
+/// One JSON-framed (newline-delimited) command sent over the control socket.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "command", rename_all = "kebab-case")]
+pub enum SocketMessage {
+    CreateWindow,
+    FocusWindow { id: WindowId },
+    CloseWindow { id: WindowId },
+    ListWindows,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SocketResponse {
+    Ok(serde_json::Value),
+    Err { error: String },
+}
+
+/// Listens on a Unix domain socket and dispatches `vibetunnel msg` commands
+/// (`create-window`, `focus-window`, `close-window`, `list-windows`) into the
+/// shared `WindowManager`, so a running instance can be scripted/controlled
+/// from a second invocation of the binary.
+pub struct SocketServer {
+    window_manager: Arc<WindowManager>,
+}
+
+impl SocketServer {
+    pub fn new(window_manager: Arc<WindowManager>) -> Self {
+        Self { window_manager }
+    }
+
+    /// Resolve the socket path, preferring `VIBETUNNEL_SOCKET` if set.
+    pub fn socket_path() -> std::path::PathBuf {
+        if let Ok(path) = std::env::var(SOCKET_ENV_VAR) {
+            return std::path::PathBuf::from(path);
+        }
+        std::env::temp_dir().join("vibetunnel.sock")
+    }
+
+    /// Start listening and serve incoming connections until the process exits.
+    pub async fn start(self: Arc<Self>, app: AppHandle) -> Result<(), String> {
+        let path = Self::socket_path();
+
+        if path.exists() {
+            let _ = std::fs::remove_file(&path);
+        }
+
+        let listener = UnixListener::bind(&path).map_err(|e| format!("Failed to bind {}: {}", path.display(), e))?;
+        std::env::set_var(SOCKET_ENV_VAR, &path);
+        info!("Listening for vibetunnel msg commands on {}", path.display());
+
+        tokio::spawn(async move {
+            loop {
+                match listener.accept().await {
+                    Ok((stream, _)) => {
+                        let server = self.clone();
+                        let app = app.clone();
+                        tokio::spawn(async move {
+                            if let Err(e) = server.handle_connection(stream, app).await {
+                                warn!("Socket connection error: {}", e);
+                            }
+                        });
+                    }
+                    Err(e) => {
+                        error!("Failed to accept socket connection: {}", e);
+                        break;
+                    }
+                }
+            }
+        });
+
+        Ok(())
+    }
+
+    async fn handle_connection(&self, stream: UnixStream, app: AppHandle) -> Result<(), String> {
+        let (reader, mut writer) = stream.into_split();
+        let mut lines = BufReader::new(reader).lines();
+
+        while let Some(line) = lines.next_line().await.map_err(|e| e.to_string())? {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let response = match serde_json::from_str::<SocketMessage>(&line) {
+                Ok(message) => self.dispatch(&app, message).await,
+                Err(e) => SocketResponse::Err {
+                    error: format!("Malformed message: {}", e),
+                },
+            };
+
+            let mut payload = serde_json::to_string(&response).map_err(|e| e.to_string())?;
+            payload.push('\n');
+            writer.write_all(payload.as_bytes()).await.map_err(|e| e.to_string())?;
+        }
+
+        Ok(())
+    }
+
+    async fn dispatch(&self, app: &AppHandle, message: SocketMessage) -> SocketResponse {
+        debug!("Handling socket message: {:?}", message);
+
+        let result = match message {
+            SocketMessage::CreateWindow => self
+                .window_manager
+                .create_window(app)
+                .await
+                .map(|id| serde_json::json!({ "id": id })),
+            SocketMessage::FocusWindow { id } => self
+                .window_manager
+                .focus_window(app, id)
+                .await
+                .map(|_| serde_json::json!({})),
+            SocketMessage::CloseWindow { id } => self
+                .window_manager
+                .close_window(app, id)
+                .await
+                .map(|_| serde_json::json!({})),
+            SocketMessage::ListWindows => {
+                let windows = self.window_manager.list_windows(app).await;
+                Ok(serde_json::json!({ "windows": windows }))
+            }
+        };
+
+        match result {
+            Ok(value) => SocketResponse::Ok(value),
+            Err(error) => SocketResponse::Err { error },
+        }
+    }
+}
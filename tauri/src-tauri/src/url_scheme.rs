@@ -1,74 +1,187 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use tauri::{Emitter, Listener, Manager};
 use tracing::{debug, error, info};
 
 /// URL scheme handler for vibetunnel:// URLs
 pub struct URLSchemeHandler;
 
+/// A route handler registered via `register_route`: given the parsed query
+/// parameters, produce the action to run (or a rejection reason).
+type RouteHandler = Box<dyn Fn(&HashMap<String, String>) -> Result<URLSchemeAction, String> + Send + Sync>;
+
+lazy_static::lazy_static! {
+    /// Handlers for `vibetunnel://<path>` routes beyond the built-in ones
+    /// (`session`, `create`, `settings`, `welcome`), keyed by path.
+    /// Subsystems register their own routes at startup via
+    /// `URLSchemeHandler::register_route` so new deep-link capabilities
+    /// don't require editing `parse_url`'s match arms.
+    static ref ROUTE_REGISTRY: std::sync::RwLock<HashMap<String, RouteHandler>> =
+        std::sync::RwLock::new(HashMap::new());
+}
+
+/// Settings tabs the frontend actually renders. `tab` is user-controlled
+/// (it comes straight off an external `vibetunnel://settings?tab=...` URL),
+/// so anything outside this list is rejected rather than interpolated into
+/// a navigation target.
+const ALLOWED_SETTINGS_TABS: &[&str] =
+    &["general", "appearance", "sessions", "notifications", "advanced", "about"];
+
+/// Reasons a `vibetunnel://` URL was rejected, so callers can distinguish
+/// "this isn't a URL we understand" from "we understood it and said no".
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UrlSchemeError {
+    /// Didn't start with `vibetunnel://`.
+    InvalidScheme,
+    /// No route matches this path.
+    UnknownPath(String),
+    /// A required query parameter was absent.
+    MissingParameter(String),
+    /// A query parameter was present but failed validation.
+    InvalidParameter { name: String, reason: String },
+    /// Recognized and well-formed, but disallowed by the current policy.
+    RejectedByPolicy(String),
+    /// A registered route handler rejected the request.
+    HandlerError(String),
+}
+
+impl std::fmt::Display for UrlSchemeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidScheme => write!(f, "invalid URL scheme: must start with vibetunnel://"),
+            Self::UnknownPath(path) => write!(f, "unknown URL path: {}", path),
+            Self::MissingParameter(name) => write!(f, "missing required parameter: {}", name),
+            Self::InvalidParameter { name, reason } => write!(f, "invalid parameter '{}': {}", name, reason),
+            Self::RejectedByPolicy(reason) => write!(f, "rejected by policy: {}", reason),
+            Self::HandlerError(reason) => write!(f, "route handler error: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for UrlSchemeError {}
+
+/// Policy gates applied to externally-triggered deep-link actions. Defaults
+/// to every action allowed; callers that expose the scheme to untrusted
+/// sources (e.g. arbitrary web links) can disable specific actions, such as
+/// `create`/`new` spawning a session running an attacker-supplied command.
+#[derive(Debug, Clone, Copy)]
+pub struct UrlSchemePolicy {
+    pub allow_create_session: bool,
+}
+
+impl Default for UrlSchemePolicy {
+    fn default() -> Self {
+        Self {
+            allow_create_session: true,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum URLSchemeAction {
     OpenSession { session_id: String },
     CreateSession { name: Option<String>, command: Option<String> },
     OpenSettings { tab: Option<String> },
     ShowWelcome,
+    /// Dispatched for a route registered via `register_route` that doesn't
+    /// need a bespoke variant of its own.
+    Custom { route: String, params: HashMap<String, String> },
 }
 
 impl URLSchemeHandler {
-    /// Parse a vibetunnel:// URL into an action
-    pub fn parse_url(url: &str) -> Result<URLSchemeAction, String> {
+    /// Parse a vibetunnel:// URL into an action, validating every
+    /// user-controlled parameter and applying `policy` to gate actions that
+    /// can have side effects (spawning a session, running a command).
+    pub fn parse_url(url: &str, policy: &UrlSchemePolicy) -> Result<URLSchemeAction, UrlSchemeError> {
         debug!("Parsing URL scheme: {}", url);
-        
+
         // Remove the scheme prefix
-        let url = url.strip_prefix("vibetunnel://")
-            .ok_or_else(|| "Invalid URL scheme: must start with vibetunnel://".to_string())?;
-        
+        let url = url.strip_prefix("vibetunnel://").ok_or(UrlSchemeError::InvalidScheme)?;
+
         // Parse the path and query
         let parts: Vec<&str> = url.split('?').collect();
         let path = parts.get(0).unwrap_or(&"");
         let query = parts.get(1).unwrap_or(&"");
-        
+
         // Parse query parameters
         let params = Self::parse_query(query);
-        
+
         // Route based on path
         match *path {
             "session" | "sessions" => {
-                if let Some(session_id) = params.get("id") {
-                    Ok(URLSchemeAction::OpenSession {
-                        session_id: session_id.clone(),
-                    })
-                } else {
-                    Err("Missing session ID parameter".to_string())
-                }
+                let session_id = params
+                    .get("id")
+                    .cloned()
+                    .ok_or_else(|| UrlSchemeError::MissingParameter("id".to_string()))?;
+                Ok(URLSchemeAction::OpenSession { session_id })
             }
             "create" | "new" => {
+                if !policy.allow_create_session {
+                    return Err(UrlSchemeError::RejectedByPolicy(
+                        "creating sessions from external URLs is disabled".to_string(),
+                    ));
+                }
                 Ok(URLSchemeAction::CreateSession {
                     name: params.get("name").cloned(),
                     command: params.get("command").cloned(),
                 })
             }
             "settings" | "preferences" => {
-                Ok(URLSchemeAction::OpenSettings {
-                    tab: params.get("tab").cloned(),
-                })
-            }
-            "welcome" => {
-                Ok(URLSchemeAction::ShowWelcome)
+                let tab = Self::validate_tab(params.get("tab"))?;
+                Ok(URLSchemeAction::OpenSettings { tab })
             }
+            "welcome" => Ok(URLSchemeAction::ShowWelcome),
             "" => {
                 // Default action - show welcome or main window
                 Ok(URLSchemeAction::ShowWelcome)
             }
-            _ => {
-                Err(format!("Unknown URL path: {}", path))
-            }
+            other => Self::dispatch_registered_route(other, &params),
         }
     }
-    
+
+    /// Register a handler for a `vibetunnel://<path>` route not covered by
+    /// the built-in ones. Re-registering an existing path replaces its
+    /// handler. Intended to be called once at startup by whichever
+    /// subsystem owns the route (window tracker, session manager, settings).
+    pub fn register_route<F>(path: impl Into<String>, handler: F)
+    where
+        F: Fn(&HashMap<String, String>) -> Result<URLSchemeAction, String> + Send + Sync + 'static,
+    {
+        ROUTE_REGISTRY.write().unwrap().insert(path.into(), Box::new(handler));
+    }
+
+    /// Look up `path` in the route registry and run its handler, falling
+    /// back to an `UnknownPath` error if nothing is registered for it.
+    fn dispatch_registered_route(path: &str, params: &HashMap<String, String>) -> Result<URLSchemeAction, UrlSchemeError> {
+        let registry = ROUTE_REGISTRY.read().unwrap();
+        match registry.get(path) {
+            Some(handler) => handler(params).map_err(UrlSchemeError::HandlerError),
+            None => Err(UrlSchemeError::UnknownPath(path.to_string())),
+        }
+    }
+
+    /// Validate a `tab` parameter against the known settings tabs. Rejects
+    /// anything unrecognized instead of letting it flow into a navigation
+    /// target, where it could otherwise break out of a quoted JS/URL literal.
+    fn validate_tab(tab: Option<&String>) -> Result<Option<String>, UrlSchemeError> {
+        let Some(tab) = tab else {
+            return Ok(None);
+        };
+
+        if ALLOWED_SETTINGS_TABS.contains(&tab.as_str()) {
+            Ok(Some(tab.clone()))
+        } else {
+            Err(UrlSchemeError::InvalidParameter {
+                name: "tab".to_string(),
+                reason: format!("unrecognized settings tab: {}", tab),
+            })
+        }
+    }
+
     /// Parse query string into key-value pairs
-    fn parse_query(query: &str) -> std::collections::HashMap<String, String> {
-        let mut params = std::collections::HashMap::new();
-        
+    fn parse_query(query: &str) -> HashMap<String, String> {
+        let mut params = HashMap::new();
+
         for pair in query.split('&') {
             if let Some((key, value)) = pair.split_once('=') {
                 if let Ok(decoded_value) = urlencoding::decode(value) {
@@ -76,10 +189,82 @@ impl URLSchemeHandler {
                 }
             }
         }
-        
+
         params
     }
-    
+
+    /// Get the "main" window, creating it (hidden) if it doesn't exist yet.
+    /// Returns whether it was freshly created, so callers can tell whether
+    /// its frontend has had a chance to attach event listeners yet.
+    fn get_or_create_main_window(app_handle: &tauri::AppHandle) -> Result<(tauri::WebviewWindow, bool), String> {
+        if let Some(window) = app_handle.get_webview_window("main") {
+            return Ok((window, false));
+        }
+
+        let window = tauri::WebviewWindowBuilder::new(app_handle, "main", tauri::WebviewUrl::App("index.html".into()))
+            .title("VibeTunnel")
+            .inner_size(1200.0, 800.0)
+            .center()
+            .resizable(true)
+            .decorations(true)
+            .build()
+            .map_err(|e| format!("Failed to create main window: {}", e))?;
+
+        Ok((window, true))
+    }
+
+    /// Show the main window, creating it first if needed. Used for actions
+    /// that have nothing to deliver to the frontend (e.g. a failed session
+    /// creation), so there's no event to sequence.
+    fn show_main_window(app_handle: &tauri::AppHandle) -> Result<(), String> {
+        let (window, _) = Self::get_or_create_main_window(app_handle)?;
+        let _ = window.show();
+        let _ = window.set_focus();
+        Ok(())
+    }
+
+    /// Show the main window and deliver `session_id` to it once its frontend
+    /// is actually listening. A freshly created webview hasn't run its JS
+    /// yet, so emitting immediately (as this used to do via a global
+    /// `emit("open-session", ...)`) could fire before any listener is
+    /// attached — and would broadcast to every window besides. Waits for the
+    /// frontend's `window-ready` handshake before delivering a targeted
+    /// `emit_to("main", ...)`, with a timeout so a frontend that never signals
+    /// doesn't drop the deep link forever.
+    async fn open_session_in_main_window(app_handle: &tauri::AppHandle, session_id: &str) -> Result<(), String> {
+        let (window, freshly_created) = Self::get_or_create_main_window(app_handle)?;
+        let _ = window.show();
+        let _ = window.set_focus();
+
+        if freshly_created {
+            Self::wait_for_window_ready(&window).await;
+        }
+
+        app_handle
+            .emit_to("main", "open-session", session_id)
+            .map_err(|e| format!("Failed to emit open-session event: {}", e))
+    }
+
+    /// Wait (with a timeout) for `window` to emit its `window-ready`
+    /// handshake, confirming the frontend has attached its listeners. Falls
+    /// through after the timeout rather than blocking a deep link forever if
+    /// the frontend never signals.
+    async fn wait_for_window_ready(window: &tauri::WebviewWindow) {
+        use tokio::sync::oneshot;
+
+        let (tx, rx) = oneshot::channel();
+        let tx = std::sync::Mutex::new(Some(tx));
+
+        let handler_id = window.listen("window-ready", move |_event| {
+            if let Some(tx) = tx.lock().unwrap().take() {
+                let _ = tx.send(());
+            }
+        });
+
+        let _ = tokio::time::timeout(std::time::Duration::from_secs(5), rx).await;
+        window.unlisten(handler_id);
+    }
+
     /// Handle a URL scheme action
     pub async fn handle_action(
         action: URLSchemeAction,
@@ -89,29 +274,7 @@ impl URLSchemeHandler {
         
         match action {
             URLSchemeAction::OpenSession { session_id } => {
-                // Open session detail window
-                app_handle.emit("open-session", &session_id)
-                    .map_err(|e| format!("Failed to emit open-session event: {}", e))?;
-                
-                // Show main window if needed (synchronous)
-                if let Some(window) = app_handle.get_webview_window("main") {
-                    let _ = window.show();
-                    let _ = window.set_focus();
-                } else {
-                    // Create main window
-                    let window = tauri::WebviewWindowBuilder::new(app_handle, "main", tauri::WebviewUrl::App("index.html".into()))
-                        .title("VibeTunnel")
-                        .inner_size(1200.0, 800.0)
-                        .center()
-                        .resizable(true)
-                        .decorations(true)
-                        .build();
-                    
-                    if let Ok(window) = window {
-                        let _ = window.show();
-                        let _ = window.set_focus();
-                    }
-                }
+                Self::open_session_in_main_window(app_handle, &session_id).await?;
             }
             URLSchemeAction::CreateSession { name, command } => {
                 // Create new session
@@ -124,36 +287,24 @@ impl URLSchemeHandler {
                     env: None,
                     shell: command,
                 };
-                
-                if let Ok(session) = state.api_client.create_session(req).await {
-                    // Emit event to open the new session
-                    app_handle.emit("open-session", &session.id)
-                        .map_err(|e| format!("Failed to emit open-session event: {}", e))?;
-                }
-                
-                // Show main window (synchronous)
-                if let Some(window) = app_handle.get_webview_window("main") {
-                    let _ = window.show();
-                    let _ = window.set_focus();
-                } else {
-                    // Create main window
-                    let window = tauri::WebviewWindowBuilder::new(app_handle, "main", tauri::WebviewUrl::App("index.html".into()))
-                        .title("VibeTunnel")
-                        .inner_size(1200.0, 800.0)
-                        .center()
-                        .resizable(true)
-                        .decorations(true)
-                        .build();
-                    
-                    if let Ok(window) = window {
-                        let _ = window.show();
-                        let _ = window.set_focus();
+
+                match state.api_client.create_session(req).await {
+                    Ok(session) => {
+                        Self::open_session_in_main_window(app_handle, &session.id).await?;
+                    }
+                    Err(e) => {
+                        error!("Failed to create session from URL scheme: {}", e);
+                        Self::show_main_window(app_handle)?;
                     }
                 }
             }
             URLSchemeAction::OpenSettings { tab } => {
-                // Open settings window
-                let url = if let Some(tab_name) = tab {
+                // Open settings window. `tab` was already validated against
+                // `ALLOWED_SETTINGS_TABS` in `parse_url`, but it's still
+                // forwarded as a structured event payload rather than
+                // interpolated into an `eval`'d navigation string, so it can
+                // never break out of a JS string literal.
+                let url = if let Some(tab_name) = &tab {
                     format!("settings.html?tab={}", tab_name)
                 } else {
                     "settings.html".to_string()
@@ -161,8 +312,7 @@ impl URLSchemeHandler {
 
                 // Check if settings window already exists
                 if let Some(window) = app_handle.get_webview_window("settings") {
-                    // Navigate to the URL with the tab parameter if window exists
-                    let _ = window.eval(&format!("window.location.href = '{}'", url));
+                    let _ = window.emit("open-settings-tab", &tab);
                     let _ = window.show();
                     let _ = window.set_focus();
                 } else {
@@ -191,6 +341,15 @@ impl URLSchemeHandler {
                     }
                 });
             }
+            URLSchemeAction::Custom { route, params } => {
+                // Registered routes don't have a bespoke window/state action
+                // of their own, so forward them to the frontend as a
+                // namespaced event and let whichever subsystem registered
+                // the route react to it.
+                app_handle
+                    .emit(&format!("url-route:{}", route), &params)
+                    .map_err(|e| format!("Failed to emit url-route event for '{}': {}", route, e))?;
+            }
         }
         
         Ok(())
@@ -208,8 +367,8 @@ impl URLSchemeHandler {
             if let Ok(urls) = serde_json::from_str::<Vec<String>>(payload) {
                 for url in urls {
                     debug!("Received deep link: {}", url);
-                    
-                    match Self::parse_url(&url) {
+
+                    match Self::parse_url(&url, &UrlSchemePolicy::default()) {
                         Ok(action) => {
                             let app_handle_clone = app_handle_for_closure.clone();
                             tauri::async_runtime::spawn(async move {
@@ -231,11 +390,11 @@ impl URLSchemeHandler {
 // Commands for testing URL scheme handling
 #[tauri::command]
 pub async fn handle_url_scheme(url: String, app: tauri::AppHandle) -> Result<(), String> {
-    let action = URLSchemeHandler::parse_url(&url)?;
+    let action = URLSchemeHandler::parse_url(&url, &UrlSchemePolicy::default()).map_err(|e| e.to_string())?;
     URLSchemeHandler::handle_action(action, &app).await
 }
 
 #[tauri::command]
 pub fn parse_url_scheme(url: String) -> Result<URLSchemeAction, String> {
-    URLSchemeHandler::parse_url(&url)
+    URLSchemeHandler::parse_url(&url, &UrlSchemePolicy::default()).map_err(|e| e.to_string())
 }
\ No newline at end of file
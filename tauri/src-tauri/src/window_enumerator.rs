@@ -12,6 +12,15 @@ pub struct WindowInfo {
     pub tab_id: Option<String>,
     pub bounds: Option<WindowBounds>,
     pub title: Option<String>,
+    /// Wayland app id (`WM_CLASS`'s Wayland analogue), when enumerated under
+    /// a Wayland session. `window_id` is meaningless there, so Wayland-aware
+    /// focusing keys off this (or `owner_pid`/`title`) instead.
+    pub app_id: Option<String>,
+    /// Opaque Wayland surface handle, when available. Currently unused by
+    /// any focusing path, but captured so a future compositor-specific
+    /// integration (e.g. a foreign-toplevel protocol client) doesn't need to
+    /// re-plumb enumeration.
+    pub wayland_surface: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -45,75 +54,148 @@ impl WindowEnumerator {
     #[cfg(target_os = "macos")]
     fn get_terminal_windows_macos() -> Vec<WindowInfo> {
         use std::process::Command;
-        let mut terminal_windows = Vec::new();
 
-        // Use AppleScript to get window information as a simpler approach
+        // One delimited line per window: appName|unixPid|windowId|title|x|y|w|h
         let script = r#"
             tell application "System Events"
                 set terminalApps to {"Terminal", "iTerm2", "Alacritty", "kitty", "WezTerm", "Hyper"}
-                set windowList to {}
-                
+                set output to {}
+
                 repeat with appName in terminalApps
                     if exists application process appName then
                         tell application process appName
+                            set appPid to unix id
                             repeat with w in windows
-                                set windowInfo to {appName, (id of w), (name of w), (position of w), (size of w)}
-                                set end of windowList to windowInfo
+                                set windowTitle to "Untitled"
+                                try
+                                    set windowTitle to name of w
+                                end try
+                                set windowPos to position of w
+                                set windowSize to size of w
+                                set windowId to id of w
+                                set end of output to (appName as string) & "|" & (appPid as string) & "|" & (windowId as string) & "|" & windowTitle & "|" & (item 1 of windowPos as string) & "|" & (item 2 of windowPos as string) & "|" & (item 1 of windowSize as string) & "|" & (item 2 of windowSize as string)
                             end repeat
                         end tell
                     end if
                 end repeat
-                
-                return windowList
+
+                set AppleScript's text item delimiters to linefeed
+                set joined to output as string
+                set AppleScript's text item delimiters to ""
+                return joined
             end tell
         "#;
 
-        if let Ok(output) = Command::new("osascript")
-            .arg("-e")
-            .arg(script)
-            .output()
-        {
-            if output.status.success() {
-                // Parse the AppleScript output
-                // This is a simplified version - real implementation would parse the structured output
-                debug!("Window enumeration via AppleScript completed");
+        let mut terminal_windows = match Command::new("osascript").arg("-e").arg(script).output() {
+            Ok(output) if output.status.success() => {
+                Self::parse_macos_window_list(&String::from_utf8_lossy(&output.stdout))
+            }
+            Ok(output) => {
+                debug!("AppleScript window enumeration failed: {}", String::from_utf8_lossy(&output.stderr));
+                Vec::new()
+            }
+            Err(e) => {
+                debug!("Failed to run osascript for window enumeration: {}", e);
+                Vec::new()
+            }
+        };
+
+        // Fill in tab_reference/tab_id for apps that support tabs (Terminal.app, iTerm2).
+        let tabs_by_window = Self::get_selected_tabs_macos();
+        for window in &mut terminal_windows {
+            if let Some(tab_id) = tabs_by_window.get(&(window.terminal_app.clone(), window.window_id)) {
+                window.tab_reference = Some(format!("tab id {} of window id {}", tab_id, window.window_id));
+                window.tab_id = Some(tab_id.clone());
             }
         }
 
-        // Fallback: Use ps to find terminal processes
-        if let Ok(output) = Command::new("ps")
-            .args(&["-eo", "pid,comm"])
-            .output()
-        {
+        terminal_windows
+    }
+
+    /// Parse the delimited `appName|pid|windowId|title|x|y|w|h` lines
+    /// produced by the macOS window enumeration AppleScript.
+    #[cfg(target_os = "macos")]
+    fn parse_macos_window_list(raw: &str) -> Vec<WindowInfo> {
+        let mut terminal_windows = Vec::new();
+
+        for line in raw.lines() {
+            let fields: Vec<&str> = line.split('|').collect();
+            if fields.len() != 8 {
+                continue;
+            }
+
+            let [app_name, pid, window_id, title, x, y, width, height] = [
+                fields[0], fields[1], fields[2], fields[3], fields[4], fields[5], fields[6], fields[7],
+            ];
+
+            let (Ok(owner_pid), Ok(window_id)) = (pid.parse::<u32>(), window_id.parse::<u64>()) else {
+                continue;
+            };
+
+            let bounds = match (x.parse::<f64>(), y.parse::<f64>(), width.parse::<f64>(), height.parse::<f64>()) {
+                (Ok(x), Ok(y), Ok(width), Ok(height)) => Some(WindowBounds { x, y, width, height }),
+                _ => None,
+            };
+
+            terminal_windows.push(WindowInfo {
+                window_id,
+                owner_pid,
+                terminal_app: app_name.to_string(),
+                session_id: String::new(),
+                created_at: chrono::Utc::now(),
+                tab_reference: None,
+                tab_id: None,
+                bounds,
+                title: Some(title.to_string()),
+                app_id: None,
+                wayland_surface: None,
+            });
+        }
+
+        terminal_windows
+    }
+
+    /// Query the selected tab id of every Terminal.app/iTerm2 window, keyed
+    /// by `(app name, window id)`.
+    #[cfg(target_os = "macos")]
+    fn get_selected_tabs_macos() -> std::collections::HashMap<(String, u64), String> {
+        use std::process::Command;
+
+        let script = r#"
+            set output to {}
+            repeat with appName in {"Terminal", "iTerm2"}
+                if application appName is running then
+                    tell application appName
+                        repeat with w in windows
+                            try
+                                set wid to id of w
+                                set ti to (selected tab of w)
+                                set end of output to appName & "|" & (wid as string) & "|" & (id of ti as string)
+                            end try
+                        end repeat
+                    end tell
+                end if
+            end repeat
+            set AppleScript's text item delimiters to linefeed
+            set joined to output as string
+            set AppleScript's text item delimiters to ""
+            return joined
+        "#;
+
+        let mut tabs = std::collections::HashMap::new();
+        if let Ok(output) = Command::new("osascript").arg("-e").arg(script).output() {
             if output.status.success() {
-                let output_str = String::from_utf8_lossy(&output.stdout);
-                for line in output_str.lines() {
-                    let parts: Vec<&str> = line.trim().split_whitespace().collect();
-                    if parts.len() >= 2 {
-                        if let Ok(pid) = parts[0].parse::<u32>() {
-                            let process_name = parts[1..].join(" ");
-                            for terminal in &["Terminal", "iTerm2", "Alacritty", "kitty", "WezTerm", "Hyper"] {
-                                if process_name.contains(terminal) {
-                                    terminal_windows.push(WindowInfo {
-                                        window_id: pid as u64,
-                                        owner_pid: pid,
-                                        terminal_app: terminal.to_string(),
-                                        session_id: String::new(),
-                                        created_at: chrono::Utc::now(),
-                                        tab_reference: None,
-                                        tab_id: None,
-                                        bounds: None,
-                                        title: None,
-                                    });
-                                }
-                            }
+                for line in String::from_utf8_lossy(&output.stdout).lines() {
+                    let fields: Vec<&str> = line.split('|').collect();
+                    if let [app_name, window_id, tab_id] = fields[..] {
+                        if let Ok(window_id) = window_id.parse::<u64>() {
+                            tabs.insert((app_name.to_string(), window_id), tab_id.to_string());
                         }
                     }
                 }
             }
         }
-
-        terminal_windows
+        tabs
     }
 
     #[cfg(target_os = "windows")]
@@ -186,6 +268,8 @@ impl WindowEnumerator {
                             tab_id: None,
                             bounds,
                             title: title_str,
+                            app_id: None,
+                            wayland_surface: None,
                         });
                     }
                 }
@@ -204,7 +288,11 @@ impl WindowEnumerator {
         let mut terminal_windows = Vec::new();
 
         // Try using wmctrl first
-        match Command::new("wmctrl").arg("-lp").output() {
+        let mut command = Command::new("wmctrl");
+        command.arg("-lp");
+        crate::linux_env::apply_to_command(&mut command);
+
+        match command.output() {
             Ok(output) => {
                 if output.status.success() {
                     let output_str = String::from_utf8_lossy(&output.stdout);
@@ -228,6 +316,8 @@ impl WindowEnumerator {
                                     tab_id: None,
                                     bounds: None,
                                     title: Some(title),
+                                    app_id: None,
+                                    wayland_surface: None,
                                 });
                             }
                         }
@@ -264,6 +354,129 @@ impl WindowEnumerator {
             false
         }
     }
+
+    /// Raise and activate the given window: AppleScript `activate` plus `perform
+    /// action "AXRaise"` on macOS, `SetForegroundWindow` on Windows, `wmctrl -i
+    /// -a <id>` on Linux.
+    pub fn focus_window(window: &WindowInfo) -> Result<(), String> {
+        #[cfg(target_os = "macos")]
+        {
+            Self::focus_window_macos(window)
+        }
+        #[cfg(target_os = "windows")]
+        {
+            Self::focus_window_windows(window)
+        }
+        #[cfg(target_os = "linux")]
+        {
+            Self::focus_window_linux(window)
+        }
+    }
+
+    /// Select the tab identified by `window.tab_id`, then focus its window.
+    /// Falls back to `focus_window` when no tab reference is available (i.e.
+    /// the terminal app doesn't support tabs, or tab lookup failed).
+    pub fn focus_tab(window: &WindowInfo) -> Result<(), String> {
+        #[cfg(target_os = "macos")]
+        {
+            if let Some(ref tab_id) = window.tab_id {
+                return Self::focus_tab_macos(window, tab_id);
+            }
+        }
+        Self::focus_window(window)
+    }
+
+    #[cfg(target_os = "macos")]
+    fn focus_window_macos(window: &WindowInfo) -> Result<(), String> {
+        use std::process::Command;
+
+        let script = format!(
+            r#"tell application "{app}" to activate
+tell application "System Events" to tell application process "{app}"
+    perform action "AXRaise" of (first window whose value of attribute "AXWindowNumber" is {id})
+end tell"#,
+            app = window.terminal_app,
+            id = window.window_id,
+        );
+
+        let output = Command::new("osascript")
+            .arg("-e")
+            .arg(&script)
+            .output()
+            .map_err(|e| format!("Failed to run osascript: {}", e))?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+        }
+    }
+
+    #[cfg(target_os = "macos")]
+    fn focus_tab_macos(window: &WindowInfo, tab_id: &str) -> Result<(), String> {
+        use std::process::Command;
+
+        let script = format!(
+            r#"tell application "{app}"
+    activate
+    repeat with w in windows
+        if (id of w as string) is "{window_id}" then
+            repeat with t in tabs of w
+                if (id of t as string) is "{tab_id}" then
+                    set selected tab of w to t
+                end if
+            end repeat
+            set frontmost of w to true
+        end if
+    end repeat
+end tell"#,
+            app = window.terminal_app,
+            window_id = window.window_id,
+            tab_id = tab_id,
+        );
+
+        let output = Command::new("osascript")
+            .arg("-e")
+            .arg(&script)
+            .output()
+            .map_err(|e| format!("Failed to run osascript: {}", e))?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+        }
+    }
+
+    #[cfg(target_os = "windows")]
+    fn focus_window_windows(window: &WindowInfo) -> Result<(), String> {
+        use windows::Win32::Foundation::HWND;
+        use windows::Win32::UI::WindowsAndMessaging::SetForegroundWindow;
+
+        let hwnd = HWND(window.window_id as isize);
+        let activated = unsafe { SetForegroundWindow(hwnd) };
+        if activated.as_bool() {
+            Ok(())
+        } else {
+            Err(format!("Failed to focus window {}", window.window_id))
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    fn focus_window_linux(window: &WindowInfo) -> Result<(), String> {
+        use std::process::Command;
+
+        let window_id = format!("0x{:x}", window.window_id);
+        let mut command = Command::new("wmctrl");
+        command.arg("-i").arg("-a").arg(&window_id);
+        crate::linux_env::apply_to_command(&mut command);
+
+        match command.output() {
+            Ok(output) if output.status.success() => Ok(()),
+            Ok(output) => Err(String::from_utf8_lossy(&output.stderr).trim().to_string()),
+            Err(e) => Err(format!("Failed to run wmctrl: {}", e)),
+        }
+    }
 }
 
 // Platform-specific imports
@@ -0,0 +1,178 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter, Manager, WebviewUrl, WebviewWindowBuilder};
+use tokio::sync::RwLock;
+use tracing::{debug, info, warn};
+
+/// Identifies one independently tracked session window.
+pub type WindowId = u64;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WindowState {
+    pub id: WindowId,
+    pub label: String,
+    pub session_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WindowSummary {
+    pub id: WindowId,
+    pub label: String,
+    pub session_id: Option<String>,
+    pub focused: bool,
+}
+
+/// Generalizes `MenubarPopover`'s single hardcoded webview into a daemon
+/// that owns and tracks any number of independent session windows, so a
+/// second CLI invocation can attach to the already-running instance instead
+/// of launching a new process.
+pub struct WindowManager {
+    windows: Arc<RwLock<HashMap<WindowId, WindowState>>>,
+    next_id: AtomicU64,
+}
+
+impl WindowManager {
+    pub fn new() -> Self {
+        Self {
+            windows: Arc::new(RwLock::new(HashMap::new())),
+            next_id: AtomicU64::new(1),
+        }
+    }
+
+    /// Create and register a new session window.
+    pub async fn create_window(&self, app: &AppHandle) -> Result<WindowId, String> {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let label = format!("session-window-{}", id);
+
+        let window = WebviewWindowBuilder::new(app, &label, WebviewUrl::App("index.html".into()))
+            .title("VibeTunnel")
+            .inner_size(900.0, 600.0)
+            .build()
+            .map_err(|e| format!("Failed to create window {}: {}", label, e))?;
+
+        let app_handle = app.clone();
+        let windows = self.windows.clone();
+        let label_for_event = label.clone();
+        window.on_window_event(move |event| {
+            if let tauri::WindowEvent::Focused(false) = event {
+                debug!("Window {} lost focus", label_for_event);
+            }
+            if let tauri::WindowEvent::CloseRequested { .. } = event {
+                let windows = windows.clone();
+                let app_handle = app_handle.clone();
+                tauri::async_runtime::spawn(async move {
+                    let mut guard = windows.write().await;
+                    guard.retain(|_, state| state.id != id);
+                    let _ = app_handle.emit("window-manager-changed", ());
+                });
+            }
+        });
+
+        self.windows.write().await.insert(
+            id,
+            WindowState {
+                id,
+                label,
+                session_id: None,
+            },
+        );
+
+        info!("Created session window {}", id);
+        Ok(id)
+    }
+
+    /// Focus a tracked window by id.
+    pub async fn focus_window(&self, app: &AppHandle, id: WindowId) -> Result<(), String> {
+        let label = {
+            let windows = self.windows.read().await;
+            windows.get(&id).map(|w| w.label.clone())
+        };
+
+        let label = label.ok_or_else(|| format!("No window tracked with id {}", id))?;
+        let window = app
+            .get_webview_window(&label)
+            .ok_or_else(|| format!("Window {} is tracked but not open", label))?;
+
+        window.show().map_err(|e| e.to_string())?;
+        window.set_focus().map_err(|e| e.to_string())
+    }
+
+    /// Close a tracked window by id.
+    pub async fn close_window(&self, app: &AppHandle, id: WindowId) -> Result<(), String> {
+        let label = {
+            let mut windows = self.windows.write().await;
+            windows.remove(&id).map(|w| w.label)
+        };
+
+        let label = label.ok_or_else(|| format!("No window tracked with id {}", id))?;
+        if let Some(window) = app.get_webview_window(&label) {
+            window.close().map_err(|e| e.to_string())?;
+        } else {
+            warn!("Window {} was tracked but already closed", label);
+        }
+        Ok(())
+    }
+
+    /// List every currently tracked window.
+    pub async fn list_windows(&self, app: &AppHandle) -> Vec<WindowSummary> {
+        let windows = self.windows.read().await;
+        windows
+            .values()
+            .map(|state| {
+                let focused = app
+                    .get_webview_window(&state.label)
+                    .and_then(|w| w.is_focused().ok())
+                    .unwrap_or(false);
+                WindowSummary {
+                    id: state.id,
+                    label: state.label.clone(),
+                    session_id: state.session_id.clone(),
+                    focused,
+                }
+            })
+            .collect()
+    }
+
+    /// Associate a window with a session id once a session attaches to it.
+    pub async fn set_session(&self, id: WindowId, session_id: String) {
+        if let Some(state) = self.windows.write().await.get_mut(&id) {
+            state.session_id = Some(session_id);
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn create_session_window(
+    app: AppHandle,
+    state: tauri::State<'_, crate::state::AppState>,
+) -> Result<WindowId, String> {
+    state.window_manager.create_window(&app).await
+}
+
+#[tauri::command]
+pub async fn focus_session_window(
+    app: AppHandle,
+    state: tauri::State<'_, crate::state::AppState>,
+    id: WindowId,
+) -> Result<(), String> {
+    state.window_manager.focus_window(&app, id).await
+}
+
+#[tauri::command]
+pub async fn close_session_window(
+    app: AppHandle,
+    state: tauri::State<'_, crate::state::AppState>,
+    id: WindowId,
+) -> Result<(), String> {
+    state.window_manager.close_window(&app, id).await
+}
+
+#[tauri::command]
+pub async fn list_session_windows(
+    app: AppHandle,
+    state: tauri::State<'_, crate::state::AppState>,
+) -> Result<Vec<WindowSummary>, String> {
+    Ok(state.window_manager.list_windows(&app).await)
+}
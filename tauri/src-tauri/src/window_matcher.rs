@@ -2,7 +2,7 @@ use crate::process_tracker::ProcessTracker;
 use crate::window_enumerator::{WindowEnumerator, WindowInfo};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use tracing::{debug, info, warn};
+use tracing::{debug, info};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SessionInfo {
@@ -11,12 +11,84 @@ pub struct SessionInfo {
     pub working_dir: String,
     pub name: Option<String>,
     pub activity_status: Option<String>,
+    /// Hostname the session is actually running on, if it differs from the
+    /// local machine (e.g. attached over SSH). `None` means local.
+    pub remote_host: Option<String>,
+    /// Set when the session's shell lives inside tmux/screen, whose process
+    /// tree dead-ends at the multiplexer server rather than reaching a
+    /// locally-owned window.
+    pub multiplexer: Option<MultiplexerSession>,
 }
 
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum MultiplexerKind {
+    Tmux,
+    Screen,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MultiplexerSession {
+    pub kind: MultiplexerKind,
+    /// tmux pane id (e.g. `"%3"`) or screen session name, identifying which
+    /// multiplexer session/pane the shell is attached to.
+    pub target: String,
+}
+
+/// Outcome of matching a session to a local window. Distinguishes "found a
+/// real local window" from "this session legitimately has none" (remote) so
+/// callers don't have to guess why `None` came back. Multiplexer sessions with
+/// no matching window come back as `NotFound`.
+pub enum WindowMatchOutcome<'a> {
+    Local(&'a WindowInfo),
+    /// The session is attached from a different host — there's no local
+    /// window for it by definition.
+    Remote,
+    NotFound,
+}
+
+impl<'a> WindowMatchOutcome<'a> {
+    pub fn window(&self) -> Option<&'a WindowInfo> {
+        match self {
+            WindowMatchOutcome::Local(window) => Some(window),
+            WindowMatchOutcome::Remote | WindowMatchOutcome::NotFound => None,
+        }
+    }
+}
+
+/// A window-id/score pair, cached per session so a later match attempt can
+/// tell whether a freshly scored candidate actually beats what's already there.
+#[derive(Debug, Clone, Copy)]
+struct MatchScore {
+    window_id: u64,
+    score: i32,
+}
+
+// Score weights. A window can match on more than one signal (e.g. both PID
+// ownership and a title match); only the strongest applicable signal counts,
+// plus a small recency tie-breaker on top.
+const SCORE_DIRECT_PID: i32 = 100;
+const SCORE_ANCESTOR_BASE: i32 = 90;
+const SCORE_ANCESTOR_DEPTH_PENALTY: i32 = 8;
+const SCORE_TAB_ID: i32 = 70;
+const SCORE_FULL_WORKING_DIR: i32 = 60;
+const SCORE_DIR_NAME: i32 = 35;
+const SCORE_ACTIVITY: i32 = 25;
+const MAX_RECENCY_BONUS: i32 = 5;
+
+/// Minimum score a candidate must clear before we trust it rather than
+/// returning `None`. Set so a bare directory-name substring match (35, plus
+/// at most the +5 tie-breaker) isn't enough on its own, but a full
+/// working-dir title match, a tab-id match, or a PID match up to ancestor
+/// depth 5 (90 - 5*8 = 50) is; deeper ancestors need another signal.
+const MATCH_THRESHOLD: i32 = 50;
+
+const MAX_ANCESTOR_DEPTH: usize = 10;
+const MAX_SELF_ANCESTOR_DEPTH: usize = 20;
+
 /// Handles window matching and session-to-window mapping algorithms
 pub struct WindowMatcher {
-    /// Cache of session to window mappings
-    session_window_cache: HashMap<String, u64>,
+    /// Cache of session to window mappings, alongside the score that won it.
+    session_window_cache: HashMap<String, MatchScore>,
 }
 
 impl WindowMatcher {
@@ -26,7 +98,8 @@ impl WindowMatcher {
         }
     }
 
-    /// Find a window for a specific terminal and session
+    /// Find a window for a specific terminal and session by scoring every
+    /// candidate window and returning the best one above `MATCH_THRESHOLD`.
     pub fn find_window<'a>(
         &mut self,
         terminal_app: &str,
@@ -36,235 +109,345 @@ impl WindowMatcher {
         tab_id: Option<&str>,
         terminal_windows: &'a [WindowInfo],
     ) -> Option<&'a WindowInfo> {
-        // Check cache first
-        if let Some(&cached_window_id) = self.session_window_cache.get(session_id) {
-            if let Some(window) = terminal_windows.iter().find(|w| w.window_id == cached_window_id) {
-                debug!("Found cached window for session {}: {}", session_id, cached_window_id);
-                return Some(window);
+        let candidates: Vec<&WindowInfo> = terminal_windows.iter().filter(|w| w.terminal_app == terminal_app).collect();
+
+        let ancestor_pids = session_info
+            .and_then(|info| info.pid)
+            .map(|pid| {
+                ProcessTracker::log_process_tree(pid, None);
+                Self::ancestors_of(pid, MAX_ANCESTOR_DEPTH)
+            })
+            .unwrap_or_default();
+
+        let working_dir = session_info.map(|info| info.working_dir.as_str()).unwrap_or("");
+        let dir_name = Self::dir_name(working_dir);
+        let activity_status = session_info.and_then(|info| info.activity_status.as_deref());
+
+        self.resolve(session_id, &candidates, |window| {
+            Self::score_window(window, &ancestor_pids, terminal_app, working_dir, &dir_name, activity_status, tab_reference, tab_id, &candidates)
+        })
+    }
+
+    /// Find a terminal window for a session that was attached via `vt`, by
+    /// the same scoring rules as `find_window` (without tab-reference signals,
+    /// which only apply when launching a window ourselves).
+    ///
+    /// Sessions inside tmux/screen or attached over SSH don't have a process
+    /// tree that reaches a locally-owned window, so those are handled as
+    /// distinct strategies rather than falling through to a PID walk that
+    /// can only dead-end.
+    pub fn find_window_for_session<'a>(&mut self, session_id: &str, session_info: &SessionInfo, all_windows: &'a [WindowInfo]) -> WindowMatchOutcome<'a> {
+        if let Some(remote_host) = session_info.remote_host.as_deref() {
+            if !Self::is_local_host(remote_host) {
+                debug!("Session {}: attached to remote host {}, skipping PID traversal", session_id, remote_host);
+                return self.resolve_remote(session_id, session_info, all_windows);
             }
         }
 
-        // Filter windows for the specific terminal
-        let filtered_windows: Vec<&WindowInfo> = terminal_windows
-            .iter()
-            .filter(|w| w.terminal_app == terminal_app)
-            .collect();
-
-        // First try to find window by process PID traversal
-        if let Some(session_info) = session_info {
-            if let Some(session_pid) = session_info.pid {
-                debug!("Attempting to find window by process PID: {}", session_pid);
-                
-                // Log the process tree for debugging
-                ProcessTracker::log_process_tree(session_pid);
-                
-                // Try to find the parent process (shell) that owns this session
-                if let Some(parent_pid) = ProcessTracker::get_parent_process_id(session_pid) {
-                    debug!("Found parent process PID: {}", parent_pid);
-                    
-                    // Look for a window owned by the parent process
-                    if let Some(matching_window) = filtered_windows.iter().find(|window| {
-                        window.owner_pid == parent_pid
-                    }) {
-                        info!("Found window by parent process match: PID {}", parent_pid);
-                        self.session_window_cache.insert(session_id.to_string(), matching_window.window_id);
-                        return Some(matching_window);
-                    }
-                    
-                    // If direct parent match fails, try to find grandparent or higher ancestors
-                    let mut current_pid = parent_pid;
-                    let mut depth = 0;
-                    while depth < 10 {
-                        if let Some(grandparent_pid) = ProcessTracker::get_parent_process_id(current_pid) {
-                            debug!("Checking ancestor process PID: {} at depth {}", grandparent_pid, depth + 2);
-                            
-                            if let Some(matching_window) = filtered_windows.iter().find(|window| {
-                                window.owner_pid == grandparent_pid
-                            }) {
-                                info!("Found window by ancestor process match: PID {} at depth {}", grandparent_pid, depth + 2);
-                                self.session_window_cache.insert(session_id.to_string(), matching_window.window_id);
-                                return Some(matching_window);
-                            }
-                            
-                            current_pid = grandparent_pid;
-                            depth += 1;
-                        } else {
-                            break;
-                        }
-                    }
+        let candidates: Vec<&WindowInfo> = all_windows.iter().collect();
+
+        let ancestor_pids = match &session_info.multiplexer {
+            Some(multiplexer) => Self::multiplexer_client_pid(multiplexer)
+                .map(|pid| {
+                    ProcessTracker::log_process_tree(pid, None);
+                    Self::self_and_ancestors(pid, MAX_SELF_ANCESTOR_DEPTH)
+                })
+                .unwrap_or_default(),
+            None => session_info
+                .pid
+                .map(|pid| {
+                    ProcessTracker::log_process_tree(pid, None);
+                    Self::self_and_ancestors(pid, MAX_SELF_ANCESTOR_DEPTH)
+                })
+                .unwrap_or_default(),
+        };
+
+        let dir_name = Self::dir_name(&session_info.working_dir);
+        let multiplexer_target = session_info.multiplexer.as_ref().map(|m| m.target.as_str());
+
+        let matched = self.resolve(session_id, &candidates, |window| {
+            let mut score = Self::score_window(
+                window,
+                &ancestor_pids,
+                "",
+                &session_info.working_dir,
+                &dir_name,
+                session_info.activity_status.as_deref(),
+                None,
+                None,
+                &candidates,
+            );
+            if let Some(target) = multiplexer_target {
+                if WindowEnumerator::window_title_contains(window, target) {
+                    score = score.max(SCORE_TAB_ID);
                 }
             }
+            score
+        });
+
+        match matched {
+            Some(window) => WindowMatchOutcome::Local(window),
+            None => WindowMatchOutcome::NotFound,
         }
+    }
+
+    /// Remote (e.g. SSH-attached) sessions have no locally-owned window, so
+    /// there's no PID signal to trust. The best we can do is a title match on
+    /// the working directory, and even that isn't cached — a coincidental
+    /// match on someone else's local terminal isn't reliable enough to pin a
+    /// session to across reconnects.
+    fn resolve_remote<'a>(&mut self, session_id: &str, session_info: &SessionInfo, all_windows: &'a [WindowInfo]) -> WindowMatchOutcome<'a> {
+        let dir_name = Self::dir_name(&session_info.working_dir);
+        let candidates: Vec<&WindowInfo> = all_windows.iter().collect();
+
+        let best = candidates
+            .iter()
+            .map(|&window| {
+                let score = Self::score_window(window, &[], "", &session_info.working_dir, &dir_name, session_info.activity_status.as_deref(), None, None, &candidates);
+                (window, score)
+            })
+            .max_by_key(|(_, score)| *score);
+
+        self.session_window_cache.remove(session_id);
 
-        // Fallback: try to find window by title containing session path or command
-        if let Some(session_info) = session_info {
-            let working_dir = &session_info.working_dir;
-            let dir_name = std::path::Path::new(working_dir)
-                .file_name()
-                .and_then(|n| n.to_str())
-                .unwrap_or("");
-            
-            // Look for windows whose title contains the directory name
-            if let Some(matching_window) = filtered_windows.iter().find(|window| {
-                WindowEnumerator::window_title_contains(window, dir_name) ||
-                WindowEnumerator::window_title_contains(window, working_dir)
-            }) {
-                debug!("Found window by directory match: {}", dir_name);
-                self.session_window_cache.insert(session_id.to_string(), matching_window.window_id);
-                return Some(matching_window);
+        match best {
+            Some((window, score)) if score >= MATCH_THRESHOLD => {
+                debug!("Session {}: remote session matched local window {} by title only (score {}), not cached", session_id, window.window_id, score);
+                WindowMatchOutcome::Local(window)
+            }
+            _ => {
+                debug!("Session {}: remote session has no local window", session_id);
+                WindowMatchOutcome::Remote
             }
         }
+    }
 
-        // For Terminal.app with specific tab reference
-        if terminal_app == "Terminal" {
-            if let Some(tab_ref) = tab_reference {
-                if let Some(window_id) = WindowEnumerator::extract_window_id(tab_ref) {
-                    if let Some(matching_window) = filtered_windows.iter().find(|w| {
-                        w.window_id == window_id
-                    }) {
-                        debug!("Found Terminal.app window by ID: {}", window_id);
-                        self.session_window_cache.insert(session_id.to_string(), matching_window.window_id);
-                        return Some(matching_window);
-                    }
-                }
-            }
+    /// Resolve the PID to walk for a multiplexer-attached session: tmux
+    /// exposes the controlling client's PID directly; screen has no
+    /// equivalent query, so callers fall back to the title match instead.
+    fn multiplexer_client_pid(multiplexer: &MultiplexerSession) -> Option<u32> {
+        match multiplexer.kind {
+            MultiplexerKind::Tmux => Self::tmux_client_pid(&multiplexer.target),
+            MultiplexerKind::Screen => None,
         }
+    }
 
-        // For iTerm2 with tab ID
-        if terminal_app == "iTerm2" {
-            if let Some(tab_id) = tab_id {
-                // Try to match by window title which often includes the window ID
-                if let Some(matching_window) = filtered_windows.iter().find(|window| {
-                    WindowEnumerator::window_title_contains(window, tab_id)
-                }) {
-                    debug!("Found iTerm2 window by ID in title: {}", tab_id);
-                    self.session_window_cache.insert(session_id.to_string(), matching_window.window_id);
-                    return Some(matching_window);
-                }
-            }
+    fn tmux_client_pid(pane: &str) -> Option<u32> {
+        let output = std::process::Command::new("tmux").args(["display-message", "-p", "-t", pane, "#{client_pid}"]).output().ok()?;
+        if !output.status.success() {
+            return None;
         }
+        String::from_utf8_lossy(&output.stdout).trim().parse().ok()
+    }
 
-        // Fallback: return the most recently created window (highest window ID)
-        if let Some(latest_window) = filtered_windows.iter().max_by_key(|w| w.window_id) {
-            debug!("Using most recent window as fallback for session: {}", session_id);
-            self.session_window_cache.insert(session_id.to_string(), latest_window.window_id);
-            return Some(latest_window);
+    fn is_local_host(host: &str) -> bool {
+        if host.is_empty() || host.eq_ignore_ascii_case("localhost") || host == "127.0.0.1" || host == "::1" {
+            return true;
         }
+        Self::local_hostname().map(|local| local.eq_ignore_ascii_case(host)).unwrap_or(false)
+    }
 
-        None
+    /// Resolve the machine's hostname via the `hostname` CLI rather than the
+    /// `HOSTNAME` environment variable, which is a non-exported shell
+    /// variable that's typically absent from a GUI app's environment (so a
+    /// `remote_host` equal to the real hostname would otherwise be
+    /// misclassified as remote and skip local PID traversal).
+    fn local_hostname() -> Option<String> {
+        let output = std::process::Command::new("hostname").output().ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        let name = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        if name.is_empty() {
+            None
+        } else {
+            Some(name)
+        }
     }
 
-    /// Find a terminal window for a session that was attached via `vt`
-    pub fn find_window_for_session<'a>(
-        &mut self,
-        session_id: &str,
-        session_info: &SessionInfo,
-        all_windows: &'a [WindowInfo],
-    ) -> Option<&'a WindowInfo> {
-        // Check cache first
-        if let Some(&cached_window_id) = self.session_window_cache.get(session_id) {
-            if let Some(window) = all_windows.iter().find(|w| w.window_id == cached_window_id) {
-                debug!("Found cached window for session {}: {}", session_id, cached_window_id);
+    /// Opt-in fallback for callers that would rather guess than come back
+    /// empty-handed: picks the most recently created window for this
+    /// terminal app even when nothing cleared the confidence threshold.
+    /// Cached with score 0, so any later scored match can freely replace it.
+    pub fn latest_window_fallback<'a>(&mut self, session_id: &str, terminal_app: &str, terminal_windows: &'a [WindowInfo]) -> Option<&'a WindowInfo> {
+        let latest = terminal_windows.iter().filter(|w| w.terminal_app == terminal_app).max_by_key(|w| w.window_id)?;
+
+        debug!("Session {}: using most recent window {} as an opt-in guess", session_id, latest.window_id);
+        self.session_window_cache.insert(
+            session_id.to_string(),
+            MatchScore {
+                window_id: latest.window_id,
+                score: 0,
+            },
+        );
+        Some(latest)
+    }
+
+    /// The score behind the currently cached match for a session, if any —
+    /// exposed so mismatches can be diagnosed without re-running the matcher.
+    pub fn cached_score(&self, session_id: &str) -> Option<i32> {
+        self.session_window_cache.get(session_id).map(|m| m.score)
+    }
+
+    /// Score every candidate, keep the cached window only if nothing beats
+    /// it above threshold, and fall back to `None` rather than guessing.
+    fn resolve<'a>(&mut self, session_id: &str, candidates: &[&'a WindowInfo], scorer: impl Fn(&WindowInfo) -> i32) -> Option<&'a WindowInfo> {
+        let best = candidates.iter().map(|&window| (window, scorer(window))).max_by_key(|(_, score)| *score);
+        let cached_score = self.session_window_cache.get(session_id).copied();
+        let cached_window = cached_score.and_then(|cached| candidates.iter().copied().find(|w| w.window_id == cached.window_id));
+
+        if let (Some((window, score)), Some(cached_score), Some(cached_window)) = (best, cached_score, cached_window) {
+            if score > cached_score.score && score >= MATCH_THRESHOLD {
+                info!(
+                    "Session {}: window match upgraded from score {} (window {}) to score {} (window {})",
+                    session_id, cached_score.score, cached_score.window_id, score, window.window_id
+                );
+                self.session_window_cache.insert(session_id.to_string(), MatchScore { window_id: window.window_id, score });
                 return Some(window);
             }
+
+            debug!("Session {}: keeping cached window {} (score {})", session_id, cached_window.window_id, cached_score.score);
+            return Some(cached_window);
         }
 
-        // First try to find window by process PID traversal
-        if let Some(session_pid) = session_info.pid {
-            debug!("Scanning for window by process PID: {} for session {}", session_pid, session_id);
-            
-            // Log the process tree for debugging
-            ProcessTracker::log_process_tree(session_pid);
-            
-            // Try to traverse up the process tree to find a terminal window
-            let mut current_pid = session_pid;
-            let mut depth = 0;
-            let max_depth = 20;
-            
-            while depth < max_depth {
-                // Check if any window is owned by this PID
-                if let Some(matching_window) = all_windows.iter().find(|window| {
-                    window.owner_pid == current_pid
-                }) {
-                    info!("Found window by PID {} at depth {} for session {}", current_pid, depth, session_id);
-                    self.session_window_cache.insert(session_id.to_string(), matching_window.window_id);
-                    return Some(matching_window);
-                }
-                
-                // Move up to parent process
-                if let Some(parent_pid) = ProcessTracker::get_parent_process_id(current_pid) {
-                    if parent_pid == 0 || parent_pid == 1 {
-                        // Reached root process
-                        break;
-                    }
-                    current_pid = parent_pid;
-                    depth += 1;
-                } else {
-                    break;
-                }
+        match best {
+            Some((window, score)) if score >= MATCH_THRESHOLD => {
+                info!("Session {}: matched window {} with score {}", session_id, window.window_id, score);
+                self.session_window_cache.insert(session_id.to_string(), MatchScore { window_id: window.window_id, score });
+                Some(window)
+            }
+            Some((window, score)) => {
+                debug!(
+                    "Session {}: best candidate window {} scored {}, below confidence threshold {}",
+                    session_id, window.window_id, score, MATCH_THRESHOLD
+                );
+                None
+            }
+            None => {
+                debug!("Session {}: no candidate windows to score", session_id);
+                None
             }
-            
-            debug!("Process traversal completed at depth {} without finding window", depth);
         }
+    }
 
-        // Fallback: Find by working directory
-        let working_dir = &session_info.working_dir;
-        let dir_name = std::path::Path::new(working_dir)
-            .file_name()
-            .and_then(|n| n.to_str())
-            .unwrap_or("");
-        
-        debug!("Trying to match by directory: {} or full path: {}", dir_name, working_dir);
-        
-        // Look for windows whose title contains the directory name
-        if let Some(matching_window) = all_windows.iter().find(|window| {
-            if let Some(ref title) = window.title {
-                let matches = title.contains(dir_name) || title.contains(working_dir);
-                if matches {
-                    debug!("Window title '{}' matches directory", title);
-                }
-                matches
-            } else {
-                false
+    /// Score a single window against every available signal, keeping only
+    /// the strongest applicable one plus a small recency tie-breaker.
+    #[allow(clippy::too_many_arguments)]
+    fn score_window(
+        window: &WindowInfo,
+        ancestor_pids: &[(u32, usize)],
+        terminal_app: &str,
+        working_dir: &str,
+        dir_name: &str,
+        activity_status: Option<&str>,
+        tab_reference: Option<&str>,
+        tab_id: Option<&str>,
+        candidates: &[&WindowInfo],
+    ) -> i32 {
+        let mut score = 0;
+
+        if let Some((_, depth)) = ancestor_pids.iter().find(|(pid, _)| *pid == window.owner_pid) {
+            score = score.max(Self::pid_score(*depth));
+        }
+
+        if !working_dir.is_empty() && WindowEnumerator::window_title_contains(window, working_dir) {
+            score = score.max(SCORE_FULL_WORKING_DIR);
+        } else if !dir_name.is_empty() && WindowEnumerator::window_title_contains(window, dir_name) {
+            score = score.max(SCORE_DIR_NAME);
+        }
+
+        if let Some(activity) = activity_status {
+            if !activity.is_empty() && WindowEnumerator::window_title_contains(window, activity) {
+                score = score.max(SCORE_ACTIVITY);
             }
-        }) {
-            info!("Found window by directory match: {} for session {}", dir_name, session_id);
-            self.session_window_cache.insert(session_id.to_string(), matching_window.window_id);
-            return Some(matching_window);
         }
 
-        // Try to match by activity status (for sessions with specific activities)
-        if let Some(ref activity) = session_info.activity_status {
-            if !activity.is_empty() {
-                debug!("Trying to match by activity: {}", activity);
-                
-                if let Some(matching_window) = all_windows.iter().find(|window| {
-                    if let Some(ref title) = window.title {
-                        title.contains(activity)
-                    } else {
-                        false
-                    }
-                }) {
-                    info!("Found window by activity match: {} for session {}", activity, session_id);
-                    self.session_window_cache.insert(session_id.to_string(), matching_window.window_id);
-                    return Some(matching_window);
+        if terminal_app == "Terminal" {
+            if let Some(window_id) = tab_reference.and_then(WindowEnumerator::extract_window_id) {
+                if window.window_id == window_id {
+                    score = score.max(SCORE_TAB_ID);
+                }
+            }
+        } else if terminal_app == "iTerm2" {
+            if let Some(tab_id) = tab_id {
+                if WindowEnumerator::window_title_contains(window, tab_id) {
+                    score = score.max(SCORE_TAB_ID);
                 }
             }
         }
 
-        warn!("Could not find window for session {} after all attempts", session_id);
-        debug!("Available windows: {}", all_windows.len());
-        for (index, window) in all_windows.iter().enumerate() {
-            debug!(
-                "  Window {}: PID={}, Terminal={}, Title={}",
-                index, 
-                window.owner_pid, 
-                window.terminal_app,
-                window.title.as_deref().unwrap_or("<no title>")
-            );
+        if score > 0 {
+            score += Self::recency_bonus(window.window_id, candidates);
+        }
+
+        score
+    }
+
+    fn pid_score(depth: usize) -> i32 {
+        if depth == 0 {
+            SCORE_DIRECT_PID
+        } else {
+            (SCORE_ANCESTOR_BASE - (depth as i32) * SCORE_ANCESTOR_DEPTH_PENALTY).max(0)
+        }
+    }
+
+    /// Up to +5, scaled by where `window_id` ranks among `candidates` —
+    /// the newest window in the candidate set gets the full bonus.
+    fn recency_bonus(window_id: u64, candidates: &[&WindowInfo]) -> i32 {
+        let ids = candidates.iter().map(|w| w.window_id);
+        let (Some(min_id), Some(max_id)) = (ids.clone().min(), ids.max()) else {
+            return 0;
+        };
+        if max_id == min_id {
+            return MAX_RECENCY_BONUS;
+        }
+
+        let ratio = (window_id - min_id) as f64 / (max_id - min_id) as f64;
+        (ratio * MAX_RECENCY_BONUS as f64).round() as i32
+    }
+
+    /// Ancestors of `start_pid`, direct parent first at depth 0.
+    fn ancestors_of(start_pid: u32, max_depth: usize) -> Vec<(u32, usize)> {
+        let mut chain = Vec::new();
+        let mut current_pid = start_pid;
+        let mut depth = 0;
+
+        while depth < max_depth {
+            let Some(parent_pid) = ProcessTracker::get_parent_process_id(current_pid) else {
+                break;
+            };
+            chain.push((parent_pid, depth));
+            current_pid = parent_pid;
+            depth += 1;
+        }
+
+        chain
+    }
+
+    /// `start_pid` itself at depth 0, then its ancestors — used where the
+    /// session's own pid (not just its parent) may directly own a window.
+    fn self_and_ancestors(start_pid: u32, max_depth: usize) -> Vec<(u32, usize)> {
+        let mut chain = vec![(start_pid, 0)];
+        let mut current_pid = start_pid;
+        let mut depth = 0;
+
+        while depth < max_depth {
+            let Some(parent_pid) = ProcessTracker::get_parent_process_id(current_pid) else {
+                break;
+            };
+            if parent_pid == 0 || parent_pid == 1 {
+                break;
+            }
+            depth += 1;
+            chain.push((parent_pid, depth));
+            current_pid = parent_pid;
         }
 
-        None
+        chain
+    }
+
+    fn dir_name(working_dir: &str) -> String {
+        std::path::Path::new(working_dir).file_name().and_then(|n| n.to_str()).unwrap_or("").to_string()
     }
 
     /// Clear cached window mapping for a session
@@ -276,4 +459,4 @@ impl WindowMatcher {
     pub fn clear_all_cache(&mut self) {
         self.session_window_cache.clear();
     }
-}
\ No newline at end of file
+}
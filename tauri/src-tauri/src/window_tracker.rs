@@ -1,8 +1,10 @@
 use crate::window_enumerator::WindowEnumerator;
 use crate::window_matcher::{SessionInfo as MatcherSessionInfo, WindowMatcher};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use std::time::Duration;
+use tauri::Emitter;
 use tokio::sync::RwLock;
 use tracing::{debug, info, warn};
 
@@ -17,6 +19,13 @@ pub struct WindowInfo {
     pub tab_id: Option<String>,
     pub bounds: Option<WindowBounds>,
     pub title: Option<String>,
+    /// Wayland app id captured at enumeration time, when available.
+    /// `window_id` is an X11 concept and meaningless under Wayland, so
+    /// Wayland-aware focusing keys off this (or `owner_pid`/`title`) instead.
+    pub app_id: Option<String>,
+    /// Opaque Wayland surface handle, when available. Currently unused by
+    /// any focusing path but carried through alongside `app_id`.
+    pub wayland_surface: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -27,11 +36,94 @@ pub struct WindowBounds {
     pub height: f64,
 }
 
+/// A display's visible rectangle in global screen coordinates, used to
+/// clamp a restored window back on-screen if the monitor it was saved on
+/// is no longer connected.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct MonitorRect {
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+}
+
+/// What gets written to `layout_path()` by `save_layout`: enough to restore
+/// a session's terminal window to where the user left it, plus the monitor
+/// it was on at save time. `restore_layout` does not read `monitor`; it
+/// clamps against the monitors connected at restore time instead.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SavedWindowLayout {
+    terminal_app: String,
+    bounds: WindowBounds,
+    monitor: Option<MonitorRect>,
+}
+
+/// Why a focus attempt didn't result in the window being raised.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FocusError {
+    /// No window is registered for the session.
+    NotRegistered(String),
+    /// The platform call itself failed (process spawn error, missing tool,
+    /// API error).
+    PlatformError(String),
+    /// The compositor understood the activation request but declined to
+    /// honor it — most Wayland compositors refuse unsolicited focus-stealing
+    /// by design, so this isn't necessarily a bug. Callers should show an
+    /// "window requested your attention" affordance instead of treating it
+    /// as a hard failure.
+    FocusRefused { reason: String },
+}
+
+impl std::fmt::Display for FocusError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FocusError::NotRegistered(session_id) => {
+                write!(f, "No window registered for session: {}", session_id)
+            }
+            FocusError::PlatformError(reason) => write!(f, "{}", reason),
+            FocusError::FocusRefused { reason } => write!(f, "{}", reason),
+        }
+    }
+}
+
+impl std::error::Error for FocusError {}
+
+/// Tunable knobs for `register_window_with_options`'s observer loop: how
+/// long to keep looking for a just-opened terminal's window before giving
+/// up, and how often to re-enumerate while looking.
+#[derive(Debug, Clone, Copy)]
+pub struct RegistrationPolicy {
+    /// Stop looking for the window after this long has elapsed.
+    pub deadline: Duration,
+    /// Interval before the first re-enumeration attempt; doubles after each
+    /// miss (capped at `MAX_REGISTRATION_POLL_INTERVAL`) so a slow terminal is still
+    /// found without polling tightly the whole time.
+    pub poll_interval: Duration,
+}
+
+impl Default for RegistrationPolicy {
+    fn default() -> Self {
+        Self {
+            deadline: Duration::from_secs(6),
+            poll_interval: Duration::from_millis(150),
+        }
+    }
+}
+
+/// Ceiling the exponential backoff in `register_window_with_options` grows
+/// to, so a long deadline doesn't turn into a handful of enumeration calls
+/// minutes apart.
+const MAX_REGISTRATION_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
 pub struct WindowTracker {
     // Maps session IDs to their terminal window information
     session_window_map: Arc<RwLock<HashMap<String, WindowInfo>>>,
     // Window matcher for advanced window finding
     window_matcher: Arc<RwLock<WindowMatcher>>,
+    // Session ids currently being searched for by `register_window_with_options`,
+    // so a concurrent call for the same session (or a racing `update_from_sessions`
+    // pass) doesn't insert a second, conflicting `WindowInfo`.
+    registrations_in_flight: Arc<RwLock<HashSet<String>>>,
 }
 
 impl WindowTracker {
@@ -39,50 +131,424 @@ impl WindowTracker {
         Self {
             session_window_map: Arc::new(RwLock::new(HashMap::new())),
             window_matcher: Arc::new(RwLock::new(WindowMatcher::new())),
+            registrations_in_flight: Arc::new(RwLock::new(HashSet::new())),
+        }
+    }
+
+    /// Where the session↔window registry is persisted across restarts.
+    fn registry_path() -> std::path::PathBuf {
+        std::env::temp_dir().join("vibetunnel-window-registry.json")
+    }
+
+    /// Where the saved window layout (positions/sizes, not the session↔window
+    /// bindings themselves) is persisted across restarts.
+    fn layout_path() -> std::path::PathBuf {
+        std::env::temp_dir().join("vibetunnel-window-layout.json")
+    }
+
+    /// Load the persisted registry from disk, if present, then immediately
+    /// `reconcile` it against live windows so dead entries don't linger.
+    pub async fn load(&self) {
+        let path = Self::registry_path();
+        let loaded: HashMap<String, WindowInfo> = match tokio::fs::read_to_string(&path).await {
+            Ok(contents) => serde_json::from_str(&contents).unwrap_or_default(),
+            Err(e) => {
+                debug!("No persisted window registry to load ({}): {}", path.display(), e);
+                HashMap::new()
+            }
+        };
+
+        *self.session_window_map.write().await = loaded;
+        self.reconcile().await;
+    }
+
+    /// Write the current registry to disk.
+    async fn persist(&self) {
+        let path = Self::registry_path();
+        let snapshot = self.session_window_map.read().await.clone();
+
+        match serde_json::to_string(&snapshot) {
+            Ok(json) => {
+                if let Err(e) = tokio::fs::write(&path, json).await {
+                    warn!("Failed to persist window registry to {}: {}", path.display(), e);
+                }
+            }
+            Err(e) => warn!("Failed to serialize window registry: {}", e),
+        }
+    }
+
+    /// Re-validate every persisted `session_id ↔ {window_id, owner_pid,
+    /// tab_reference}` association against the windows actually on screen:
+    /// entries whose owning process is dead or whose window can no longer be
+    /// found are pruned. Survivors are kept as stored; their window id,
+    /// title and bounds are not refreshed from the live window. Runs on startup (via
+    /// `load`) and should also be called on each enumeration pass.
+    pub async fn reconcile(&self) {
+        let live_windows = WindowEnumerator::get_all_terminal_windows();
+
+        let stale_sessions: Vec<String> = {
+            let window_map = self.session_window_map.read().await;
+            window_map
+                .iter()
+                .filter_map(|(session_id, stored)| {
+                    let pid_alive = crate::process_tracker::ProcessTracker::get_process_info(stored.owner_pid).is_some();
+                    if !pid_alive {
+                        return Some(session_id.clone());
+                    }
+
+                    let still_present = live_windows.iter().any(|live| {
+                        live.window_id == stored.window_id
+                            || stored
+                                .tab_reference
+                                .as_deref()
+                                .and_then(WindowEnumerator::extract_window_id)
+                                .map(|id| id == live.window_id)
+                                .unwrap_or(false)
+                            || stored
+                                .title
+                                .as_ref()
+                                .map(|title| WindowEnumerator::window_title_contains(live, title))
+                                .unwrap_or(false)
+                    });
+
+                    if still_present {
+                        None
+                    } else {
+                        Some(session_id.clone())
+                    }
+                })
+                .collect()
+        };
+
+        if !stale_sessions.is_empty() {
+            let mut window_map = self.session_window_map.write().await;
+            for session_id in &stale_sessions {
+                window_map.remove(session_id);
+                debug!("Pruned dead window registration for session: {}", session_id);
+            }
+            drop(window_map);
+            self.persist().await;
+        }
+    }
+
+    /// Snapshot each tracked session's terminal app + bounds + monitor to
+    /// disk, so window positions survive a restart. Sessions with no known
+    /// bounds (the matcher couldn't read them) are skipped.
+    pub async fn save_layout(&self) {
+        let monitors = Self::visible_monitors();
+        let window_map = self.session_window_map.read().await;
+
+        let layout: HashMap<String, SavedWindowLayout> = window_map
+            .iter()
+            .filter_map(|(session_id, info)| {
+                let bounds = info.bounds.clone()?;
+                let monitor = monitors.iter().find(|m| Self::rects_overlap(&bounds, m)).copied();
+                Some((
+                    session_id.clone(),
+                    SavedWindowLayout {
+                        terminal_app: info.terminal_app.clone(),
+                        bounds,
+                        monitor,
+                    },
+                ))
+            })
+            .collect();
+        drop(window_map);
+
+        let path = Self::layout_path();
+        match serde_json::to_string(&layout) {
+            Ok(json) => {
+                if let Err(e) = tokio::fs::write(&path, json).await {
+                    warn!("Failed to persist window layout to {}: {}", path.display(), e);
+                }
+            }
+            Err(e) => warn!("Failed to serialize window layout: {}", e),
+        }
+    }
+
+    /// Reposition every session's tracked window to its saved rectangle,
+    /// clamping any that would land fully off the current display set back
+    /// on-screen. Sessions with no registered window (not yet found, or the
+    /// terminal was closed) are silently skipped.
+    pub async fn restore_layout(&self) {
+        let path = Self::layout_path();
+        let layout: HashMap<String, SavedWindowLayout> = match tokio::fs::read_to_string(&path).await {
+            Ok(contents) => serde_json::from_str(&contents).unwrap_or_default(),
+            Err(e) => {
+                debug!("No persisted window layout to restore ({}): {}", path.display(), e);
+                return;
+            }
+        };
+
+        for (session_id, saved) in layout {
+            if let Err(e) = self.apply_bounds(&session_id, saved.bounds).await {
+                debug!("Could not restore layout for session {}: {}", session_id, e);
+            }
+        }
+    }
+
+    /// Move and resize the window registered to `session_id` to `bounds`,
+    /// clamping it back on-screen first if it falls entirely outside every
+    /// currently connected monitor.
+    pub async fn apply_bounds(&self, session_id: &str, bounds: WindowBounds) -> Result<(), String> {
+        let window_info = self
+            .window_info(session_id)
+            .await
+            .ok_or_else(|| format!("No window registered for session: {}", session_id))?;
+
+        let monitors = Self::visible_monitors();
+        let bounds = Self::clamp_to_monitors(bounds, &monitors);
+
+        #[cfg(target_os = "macos")]
+        {
+            self.apply_bounds_macos(&window_info, &bounds).await
+        }
+        #[cfg(target_os = "windows")]
+        {
+            self.apply_bounds_windows(&window_info, &bounds).await
+        }
+        #[cfg(target_os = "linux")]
+        {
+            self.apply_bounds_linux(&window_info, &bounds).await
+        }
+    }
+
+    /// Whether `bounds` overlaps `monitor` at all.
+    fn rects_overlap(bounds: &WindowBounds, monitor: &MonitorRect) -> bool {
+        bounds.x < monitor.x + monitor.width
+            && bounds.x + bounds.width > monitor.x
+            && bounds.y < monitor.y + monitor.height
+            && bounds.y + bounds.height > monitor.y
+    }
+
+    /// If `bounds` doesn't overlap any currently connected monitor, pull it
+    /// back onto the first one, shrinking it to fit if it's larger than the
+    /// monitor itself. Left untouched if it's already on-screen (or if we
+    /// couldn't enumerate any monitors at all).
+    fn clamp_to_monitors(bounds: WindowBounds, monitors: &[MonitorRect]) -> WindowBounds {
+        if monitors.is_empty() || monitors.iter().any(|m| Self::rects_overlap(&bounds, m)) {
+            return bounds;
+        }
+
+        let target = &monitors[0];
+        let width = bounds.width.min(target.width);
+        let height = bounds.height.min(target.height);
+        let max_x = (target.x + target.width - width).max(target.x);
+        let max_y = (target.y + target.height - height).max(target.y);
+
+        WindowBounds {
+            x: bounds.x.clamp(target.x, max_x),
+            y: bounds.y.clamp(target.y, max_y),
+            width,
+            height,
         }
     }
 
-    /// Register a terminal window for a session
+    /// Enumerate the visible rectangle of every currently connected monitor,
+    /// in global screen coordinates. Returns an empty list (rather than
+    /// erroring) if it can't be determined, in which case bounds are left
+    /// unclamped.
+    #[cfg(target_os = "macos")]
+    fn visible_monitors() -> Vec<MonitorRect> {
+        use std::process::Command;
+
+        // No pure-Rust NSScreen binding is linked, so read monitor frames
+        // through the same JXA bridge used elsewhere for AppKit queries.
+        let script = r#"
+            ObjC.import('AppKit');
+            const screens = $.NSScreen.screens;
+            const lines = [];
+            for (let i = 0; i < screens.count; i++) {
+                const frame = screens.objectAtIndex(i).frame;
+                lines.push([frame.origin.x, frame.origin.y, frame.size.width, frame.size.height].join(','));
+            }
+            lines.join('\n');
+        "#;
+
+        let output = Command::new("osascript").arg("-l").arg("JavaScript").arg("-e").arg(script).output();
+        let Ok(output) = output else {
+            return Vec::new();
+        };
+        if !output.status.success() {
+            return Vec::new();
+        }
+
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|line| {
+                let parts: Vec<f64> = line.split(',').filter_map(|p| p.parse().ok()).collect();
+                match parts.as_slice() {
+                    [x, y, width, height] => Some(MonitorRect { x: *x, y: *y, width: *width, height: *height }),
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+
+    #[cfg(target_os = "windows")]
+    fn visible_monitors() -> Vec<MonitorRect> {
+        #[cfg(windows)]
+        {
+            use windows::Win32::Foundation::{BOOL, LPARAM, RECT};
+            use windows::Win32::Graphics::Gdi::{EnumDisplayMonitors, HDC, HMONITOR};
+
+            unsafe extern "system" fn collect(_monitor: HMONITOR, _dc: HDC, rect: *mut RECT, data: LPARAM) -> BOOL {
+                let monitors = &mut *(data.0 as *mut Vec<MonitorRect>);
+                let r = *rect;
+                monitors.push(MonitorRect {
+                    x: r.left as f64,
+                    y: r.top as f64,
+                    width: (r.right - r.left) as f64,
+                    height: (r.bottom - r.top) as f64,
+                });
+                BOOL(1)
+            }
+
+            let mut monitors: Vec<MonitorRect> = Vec::new();
+            unsafe {
+                let _ = EnumDisplayMonitors(HDC(0), None, Some(collect), LPARAM(&mut monitors as *mut _ as isize));
+            }
+            monitors
+        }
+        #[cfg(not(windows))]
+        {
+            Vec::new()
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    fn visible_monitors() -> Vec<MonitorRect> {
+        use std::process::Command;
+
+        let output = Command::new("xrandr").arg("--query").output();
+        let Ok(output) = output else {
+            return Vec::new();
+        };
+        if !output.status.success() {
+            return Vec::new();
+        }
+
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter(|line| line.contains(" connected"))
+            .filter_map(Self::parse_xrandr_geometry)
+            .collect()
+    }
+
+    /// Parse the `WxH+X+Y` geometry token out of an `xrandr --query` line
+    /// for a connected output, e.g. `HDMI-1 connected primary 1920x1080+0+0 ...`.
+    #[cfg(target_os = "linux")]
+    fn parse_xrandr_geometry(line: &str) -> Option<MonitorRect> {
+        let geometry = line.split_whitespace().find(|token| token.contains('x') && token.contains('+'))?;
+        let (size, rest) = geometry.split_once('+')?;
+        let (width, height) = size.split_once('x')?;
+        let (x, y) = rest.split_once('+')?;
+
+        Some(MonitorRect {
+            x: x.parse().ok()?,
+            y: y.parse().ok()?,
+            width: width.parse().ok()?,
+            height: height.parse().ok()?,
+        })
+    }
+
+    /// Find the live window (window id, owner pid, tab reference) bound to
+    /// `session_id`, reconciling against the persisted registry first.
+    pub async fn resolve_window_for_session(&self, session_id: &str) -> Option<WindowInfo> {
+        self.reconcile().await;
+        self.session_window_map.read().await.get(session_id).cloned()
+    }
+
+    /// Find which session (if any) owns the given window id.
+    pub async fn resolve_session_for_window(&self, window_id: u64) -> Option<String> {
+        self.session_window_map
+            .read()
+            .await
+            .iter()
+            .find(|(_, info)| info.window_id == window_id)
+            .map(|(session_id, _)| session_id.clone())
+    }
+
+    /// Register a terminal window for a session, using the default
+    /// `RegistrationPolicy`. See `register_window_with_options`.
     pub async fn register_window(
         &self,
         session_id: String,
         terminal_app: String,
         tab_reference: Option<String>,
         tab_id: Option<String>,
+        app_handle: &tauri::AppHandle,
     ) {
-        info!("Registering window for session: {}, terminal: {}", session_id, terminal_app);
+        self.register_window_with_options(
+            session_id,
+            terminal_app,
+            tab_reference,
+            tab_id,
+            RegistrationPolicy::default(),
+            app_handle,
+        )
+        .await;
+    }
 
-        // For terminals with explicit window/tab info, register immediately
-        if (terminal_app == "Terminal" && tab_reference.is_some()) ||
-           (terminal_app == "iTerm2" && tab_id.is_some()) {
-            tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
-            
-            if let Some(window_info) = self.find_window(&terminal_app, &session_id, &tab_reference, &tab_id).await {
-                self.session_window_map.write().await.insert(session_id.clone(), window_info);
-                info!("Successfully registered window for session {} with explicit ID", session_id);
-            }
+    /// Observe for a just-opened terminal's window and register it the
+    /// instant `WindowMatcher` finds a match, rather than sleeping through a
+    /// fixed delay schedule: re-enumerates on an interval that starts at
+    /// `policy.poll_interval` and backs off exponentially, until either a
+    /// match is found or `policy.deadline` elapses. Emits `window-registered`
+    /// or `window-registration-failed` (both carrying the session id) when it
+    /// resolves, so the frontend doesn't have to poll for the result either.
+    ///
+    /// Deduplicates against both a concurrent call for the same session and a
+    /// racing `update_from_sessions` pass, so they don't insert conflicting
+    /// `WindowInfo`s for the same session.
+    pub async fn register_window_with_options(
+        &self,
+        session_id: String,
+        terminal_app: String,
+        tab_reference: Option<String>,
+        tab_id: Option<String>,
+        policy: RegistrationPolicy,
+        app_handle: &tauri::AppHandle,
+    ) {
+        if !self.registrations_in_flight.write().await.insert(session_id.clone()) {
+            debug!("Registration already in flight for session {}, skipping", session_id);
             return;
         }
 
-        // For other terminals, use progressive delays to find the window
-        let delays = [0.5, 1.0, 2.0, 3.0];
-        for (index, delay) in delays.iter().enumerate() {
-            tokio::time::sleep(tokio::time::Duration::from_secs_f64(*delay)).await;
-            
+        info!("Registering window for session: {}, terminal: {}", session_id, terminal_app);
+
+        let deadline = tokio::time::Instant::now() + policy.deadline;
+        let mut interval = policy.poll_interval;
+
+        loop {
             if let Some(window_info) = self.find_window(&terminal_app, &session_id, &tab_reference, &tab_id).await {
                 self.session_window_map.write().await.insert(session_id.clone(), window_info);
-                info!("Successfully registered window for session {} after {} attempts", session_id, index + 1);
+                self.persist().await;
+                info!("Successfully registered window for session {}", session_id);
+                let _ = app_handle.emit("window-registered", &session_id);
+                self.registrations_in_flight.write().await.remove(&session_id);
                 return;
             }
+
+            let now = tokio::time::Instant::now();
+            if now >= deadline {
+                break;
+            }
+
+            tokio::time::sleep(interval.min(deadline - now)).await;
+            interval = (interval * 2).min(MAX_REGISTRATION_POLL_INTERVAL);
         }
 
-        warn!("Failed to register window for session {} after all attempts", session_id);
+        warn!("Failed to register window for session {} before deadline", session_id);
+        let _ = app_handle.emit("window-registration-failed", &session_id);
+        self.registrations_in_flight.write().await.remove(&session_id);
     }
 
     /// Unregister a window for a session
     pub async fn unregister_window(&self, session_id: &str) {
         if self.session_window_map.write().await.remove(session_id).is_some() {
             info!("Unregistered window for session: {}", session_id);
+            self.persist().await;
         }
     }
 
@@ -97,20 +563,20 @@ impl WindowTracker {
     }
 
     /// Focus the terminal window for a specific session
-    pub async fn focus_window(&self, session_id: &str) -> Result<(), String> {
+    pub async fn focus_window(&self, session_id: &str) -> Result<(), FocusError> {
         let window_info = self.window_info(session_id).await
-            .ok_or_else(|| format!("No window registered for session: {}", session_id))?;
+            .ok_or_else(|| FocusError::NotRegistered(session_id.to_string()))?;
 
         info!("Focusing window for session: {}, terminal: {}", session_id, window_info.terminal_app);
 
         // Platform-specific window focusing
         #[cfg(target_os = "macos")]
         {
-            self.focus_window_macos(&window_info).await
+            self.focus_window_macos(&window_info).await.map_err(FocusError::PlatformError)
         }
         #[cfg(target_os = "windows")]
         {
-            self.focus_window_windows(&window_info).await
+            self.focus_window_windows(&window_info).await.map_err(FocusError::PlatformError)
         }
         #[cfg(target_os = "linux")]
         {
@@ -120,6 +586,10 @@ impl WindowTracker {
 
     /// Update window tracking based on current sessions
     pub async fn update_from_sessions(&self, sessions: &[crate::api_client::SessionResponse]) {
+        // Reconcile the persisted registry against live windows first, so
+        // stale entries (dead process, closed window) don't shadow a fresh match.
+        self.reconcile().await;
+
         let session_ids: std::collections::HashSet<String> = sessions.iter()
             .map(|s| s.id.clone())
             .collect();
@@ -127,28 +597,42 @@ impl WindowTracker {
         // Remove windows for sessions that no longer exist
         let mut window_map = self.session_window_map.write().await;
         let tracked_sessions: Vec<String> = window_map.keys().cloned().collect();
-        
+        let mut changed = false;
+
         for session_id in tracked_sessions {
             if !session_ids.contains(&session_id) {
                 window_map.remove(&session_id);
+                changed = true;
                 info!("Removed window tracking for terminated session: {}", session_id);
             }
         }
         drop(window_map);
 
-        // Try to find windows for sessions without registered windows
+        // Try to find windows for sessions without registered windows, skipping
+        // any a concurrent `register_window_with_options` call is already
+        // searching for, so the two don't race to insert conflicting `WindowInfo`s.
         for session in sessions {
             if self.window_info(&session.id).await.is_none() {
+                if self.registrations_in_flight.read().await.contains(&session.id) {
+                    debug!("Registration already in flight for session {}, skipping", session.id);
+                    continue;
+                }
+
                 debug!("Session {} has no window registered, attempting to find it...", session.id);
-                
+
                 if let Some(window_info) = self.find_window_for_session(&session.id).await {
                     self.session_window_map.write().await.insert(session.id.clone(), window_info);
+                    changed = true;
                     info!("Found and registered window for session: {}", session.id);
                 } else {
                     debug!("Could not find window for session: {}", session.id);
                 }
             }
         }
+
+        if changed {
+            self.persist().await;
+        }
     }
 
     // Advanced window finding using the new components
@@ -192,6 +676,8 @@ impl WindowTracker {
                     height: b.height,
                 }),
                 title: matched_window.title.clone(),
+                app_id: matched_window.app_id.clone(),
+                wayland_surface: matched_window.wayland_surface.clone(),
             })
         } else {
             None
@@ -209,16 +695,17 @@ impl WindowTracker {
             working_dir: String::new(),
             name: None,
             activity_status: None,
+            remote_host: None, // Would be filled from actual session data
+            multiplexer: None, // Would be filled from actual session data
         };
-        
+
         // Use WindowMatcher to find the window
         let mut matcher = self.window_matcher.write().await;
-        
-        if let Some(matched_window) = matcher.find_window_for_session(
-            session_id,
-            &session_info,
-            &terminal_windows,
-        ) {
+
+        if let Some(matched_window) = matcher
+            .find_window_for_session(session_id, &session_info, &terminal_windows)
+            .window()
+        {
             // Convert from EnumeratedWindowInfo to our WindowInfo
             Some(WindowInfo {
                 window_id: matched_window.window_id,  // No cast needed, already u64
@@ -235,6 +722,8 @@ impl WindowTracker {
                     height: b.height,
                 }),
                 title: matched_window.title.clone(),
+                app_id: matched_window.app_id.clone(),
+                wayland_surface: matched_window.wayland_surface.clone(),
             })
         } else {
             None
@@ -284,6 +773,35 @@ impl WindowTracker {
         Ok(())
     }
 
+    #[cfg(target_os = "macos")]
+    async fn apply_bounds_macos(&self, window_info: &WindowInfo, bounds: &WindowBounds) -> Result<(), String> {
+        use std::process::Command;
+
+        // AppleScript's "bounds" property is {left, top, right, bottom}, not
+        // {x, y, width, height}.
+        let left = bounds.x as i64;
+        let top = bounds.y as i64;
+        let right = (bounds.x + bounds.width) as i64;
+        let bottom = (bounds.y + bounds.height) as i64;
+
+        let script = format!(
+            r#"tell application "{}" to set bounds of window id {} to {{{}, {}, {}, {}}}"#,
+            window_info.terminal_app, window_info.window_id, left, top, right, bottom
+        );
+
+        let output = Command::new("osascript")
+            .arg("-e")
+            .arg(&script)
+            .output()
+            .map_err(|e| format!("Failed to run AppleScript: {}", e))?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            Err(format!("AppleScript failed: {}", String::from_utf8_lossy(&output.stderr)))
+        }
+    }
+
     #[cfg(target_os = "windows")]
     async fn focus_window_windows(&self, window_info: &WindowInfo) -> Result<(), String> {
         // Use Windows API to focus window
@@ -305,23 +823,111 @@ impl WindowTracker {
         }
     }
 
+    #[cfg(target_os = "windows")]
+    async fn apply_bounds_windows(&self, window_info: &WindowInfo, bounds: &WindowBounds) -> Result<(), String> {
+        #[cfg(windows)]
+        {
+            use windows::Win32::Foundation::HWND;
+            use windows::Win32::UI::WindowsAndMessaging::{SetWindowPos, SWP_NOACTIVATE, SWP_NOZORDER};
+
+            let hwnd = HWND(window_info.window_id as isize);
+            let result = unsafe {
+                SetWindowPos(
+                    hwnd,
+                    HWND(0),
+                    bounds.x as i32,
+                    bounds.y as i32,
+                    bounds.width as i32,
+                    bounds.height as i32,
+                    SWP_NOZORDER | SWP_NOACTIVATE,
+                )
+            };
+
+            if result.is_ok() {
+                Ok(())
+            } else {
+                Err("SetWindowPos failed".to_string())
+            }
+        }
+        #[cfg(not(windows))]
+        {
+            Err("Window positioning not implemented for Windows".to_string())
+        }
+    }
+
     #[cfg(target_os = "linux")]
-    async fn focus_window_linux(&self, window_info: &WindowInfo) -> Result<(), String> {
+    async fn apply_bounds_linux(&self, window_info: &WindowInfo, bounds: &WindowBounds) -> Result<(), String> {
         use std::process::Command;
-        
+
+        let geometry = format!(
+            "0,{},{},{},{}",
+            bounds.x as i64, bounds.y as i64, bounds.width as i64, bounds.height as i64
+        );
+
+        let wmctrl_ok = Command::new("wmctrl")
+            .arg("-i")
+            .arg("-r")
+            .arg(format!("0x{:x}", window_info.window_id))
+            .arg("-e")
+            .arg(&geometry)
+            .output()
+            .map(|r| r.status.success())
+            .unwrap_or(false);
+
+        if wmctrl_ok {
+            return Ok(());
+        }
+
+        // Fall back to xdotool, which takes position and size as separate calls.
+        let window_id = window_info.window_id.to_string();
+        let move_ok = Command::new("xdotool")
+            .arg("windowmove")
+            .arg(&window_id)
+            .arg((bounds.x as i64).to_string())
+            .arg((bounds.y as i64).to_string())
+            .output()
+            .map(|r| r.status.success())
+            .unwrap_or(false);
+
+        let resize_ok = Command::new("xdotool")
+            .arg("windowsize")
+            .arg(&window_id)
+            .arg((bounds.width as i64).to_string())
+            .arg((bounds.height as i64).to_string())
+            .output()
+            .map(|r| r.status.success())
+            .unwrap_or(false);
+
+        if move_ok && resize_ok {
+            Ok(())
+        } else {
+            Err("Failed to apply window bounds on Linux".to_string())
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    async fn focus_window_linux(&self, window_info: &WindowInfo) -> Result<(), FocusError> {
+        // wmctrl/xdotool address windows by their X11 id, which doesn't
+        // exist under Wayland — go through the compositor's own IPC instead.
+        if Self::is_wayland_session() {
+            return Self::focus_window_wayland(window_info);
+        }
+
+        use std::process::Command;
+
         // Try using wmctrl to focus the window
         let output = Command::new("wmctrl")
             .arg("-i")
             .arg("-a")
             .arg(format!("0x{:x}", window_info.window_id))
             .output();
-            
+
         match output {
             Ok(result) => {
                 if result.status.success() {
                     Ok(())
                 } else {
-                    Err("wmctrl failed to focus window".to_string())
+                    Err(FocusError::PlatformError("wmctrl failed to focus window".to_string()))
                 }
             }
             Err(_) => {
@@ -330,12 +936,157 @@ impl WindowTracker {
                     .arg("windowactivate")
                     .arg(window_info.window_id.to_string())
                     .output();
-                    
+
                 match xdotool_output {
                     Ok(result) if result.status.success() => Ok(()),
-                    _ => Err("Failed to focus window on Linux".to_string())
+                    _ => Err(FocusError::PlatformError("Failed to focus window on Linux".to_string())),
                 }
             }
         }
     }
+
+    /// True when running under a Wayland session, per the same two env vars
+    /// desktop apps conventionally check (`XDG_SESSION_TYPE` is the formal
+    /// signal; `WAYLAND_DISPLAY` covers compositors that don't set it).
+    #[cfg(target_os = "linux")]
+    fn is_wayland_session() -> bool {
+        std::env::var("WAYLAND_DISPLAY").is_ok()
+            || std::env::var("XDG_SESSION_TYPE")
+                .map(|session_type| session_type == "wayland")
+                .unwrap_or(false)
+    }
+
+    /// Ask the compositor to raise the window, keyed off `owner_pid`/`title`
+    /// rather than `window_id` (meaningless under Wayland). Tries GNOME
+    /// Shell's `Eval` interface, then KWin's scripting interface; neither is
+    /// guaranteed to be available or to honor the request; most Wayland
+    /// compositors refuse unsolicited focus-stealing by design, so that's
+    /// reported as `FocusRefused` rather than `PlatformError`.
+    #[cfg(target_os = "linux")]
+    fn focus_window_wayland(window_info: &WindowInfo) -> Result<(), FocusError> {
+        use zbus::blocking::Connection;
+
+        let connection = Connection::session()
+            .map_err(|e| FocusError::PlatformError(format!("No session D-Bus available: {}", e)))?;
+
+        if let Some(result) = Self::focus_via_gnome_shell(&connection, window_info) {
+            return result;
+        }
+
+        if let Some(result) = Self::focus_via_kwin(&connection, window_info) {
+            return result;
+        }
+
+        Err(FocusError::FocusRefused {
+            reason: "No supported Wayland focus-activation interface was available".to_string(),
+        })
+    }
+
+    /// Try GNOME Shell's `org.gnome.Shell.Eval`, which only works when
+    /// "Looking Glass" unsafe mode is enabled
+    /// (`gsettings set org.gnome.shell development-tools true`). Returns
+    /// `None` (rather than an error) when the interface itself isn't
+    /// reachable, so the caller falls through to the KWin path.
+    #[cfg(target_os = "linux")]
+    fn focus_via_gnome_shell(connection: &zbus::blocking::Connection, window_info: &WindowInfo) -> Option<Result<(), FocusError>> {
+        let pid = window_info.owner_pid;
+        let title = window_info.title.clone().unwrap_or_default().replace('\\', "\\\\").replace('\'', "\\'");
+
+        let script = format!(
+            "(function() {{ \
+                const actors = global.get_window_actors(); \
+                for (let i = 0; i < actors.length; i++) {{ \
+                    const w = actors[i].meta_window; \
+                    if (w.get_pid() === {pid} || w.get_title().includes('{title}')) {{ \
+                        w.activate(global.get_current_time()); \
+                        return true; \
+                    }} \
+                }} \
+                return false; \
+            }})()",
+            pid = pid,
+            title = title,
+        );
+
+        let reply = connection
+            .call_method(Some("org.gnome.Shell"), "/org/gnome/Shell", Some("org.gnome.Shell"), "Eval", &(script,))
+            .ok()?;
+        let (eval_succeeded, result): (bool, String) = reply.body().deserialize().ok()?;
+
+        if !eval_succeeded {
+            // Eval is disabled (unsafe mode off) rather than the match
+            // failing; let KWin have a try instead of reporting refusal.
+            return None;
+        }
+
+        if result == "true" {
+            Some(Ok(()))
+        } else {
+            Some(Err(FocusError::FocusRefused {
+                reason: "GNOME Shell found no matching window to activate".to_string(),
+            }))
+        }
+    }
+
+    /// Try KWin's scripting D-Bus interface: load a short script that
+    /// activates the client matching `owner_pid`, run it once, then unload
+    /// it. Returns `None` when the interface itself isn't reachable (not
+    /// running under KWin).
+    #[cfg(target_os = "linux")]
+    fn focus_via_kwin(connection: &zbus::blocking::Connection, window_info: &WindowInfo) -> Option<Result<(), FocusError>> {
+        let pid = window_info.owner_pid;
+        let script_body = format!(
+            "const clients = workspace.windowList(); \
+             for (let i = 0; i < clients.length; i++) {{ \
+                 if (clients[i].pid === {pid}) {{ \
+                     workspace.activeWindow = clients[i]; \
+                     break; \
+                 }} \
+             }}",
+            pid = pid,
+        );
+
+        let mut script_path = std::env::temp_dir();
+        script_path.push(format!("vibetunnel-kwin-focus-{}.js", pid));
+        std::fs::write(&script_path, script_body).ok()?;
+        let script_path_str = script_path.to_str()?;
+
+        let load_reply = connection
+            .call_method(Some("org.kde.KWin"), "/Scripting", Some("org.kde.kwin.Scripting"), "loadScript", &(script_path_str, script_path_str))
+            .ok();
+        let load_reply = match load_reply {
+            Some(reply) => reply,
+            None => {
+                let _ = std::fs::remove_file(&script_path);
+                return None;
+            }
+        };
+
+        let script_id: i32 = load_reply.body().deserialize().ok()?;
+        if script_id < 0 {
+            let _ = std::fs::remove_file(&script_path);
+            return None;
+        }
+
+        let run_result = connection.call_method(
+            Some("org.kde.KWin"),
+            format!("/Scripting/Script{}", script_id).as_str(),
+            Some("org.kde.kwin.Script"),
+            "run",
+            &(),
+        );
+        let _ = connection.call_method(
+            Some("org.kde.KWin"),
+            "/Scripting",
+            Some("org.kde.kwin.Scripting"),
+            "unloadScript",
+            &(script_path_str,),
+        );
+        let _ = std::fs::remove_file(&script_path);
+
+        match run_result {
+            Ok(_) => Some(Ok(())),
+            Err(e) => Some(Err(FocusError::PlatformError(format!("KWin script run failed: {}", e)))),
+        }
+    }
 }
\ No newline at end of file